@@ -34,6 +34,15 @@
 //! }
 //! ```
 //!
+//! Apps that use both can share one event store with [`EventKit`]:
+//!
+//! ```rust,no_run
+//! let kit = eventkit::EventKit::new();
+//! let todos = kit.reminders().fetch_incomplete_reminders()?;
+//! let today = kit.events().fetch_today_events()?;
+//! # Ok::<(), eventkit::EventKitError>(())
+//! ```
+//!
 //! ## Platform Support
 //!
 //! This library only works on macOS. It requires macOS 10.14 or later for full functionality.
@@ -48,14 +57,36 @@
 //! - `NSCalendarsUsageDescription` - for calendar access (older macOS)
 
 use block2::RcBlock;
-use chrono::{DateTime, Duration, Local, TimeZone};
+use chrono::{
+    DateTime, Datelike, Days, Duration, FixedOffset, Local, Months, NaiveDate, NaiveDateTime,
+    NaiveTime, TimeZone, Timelike, Weekday,
+};
+use objc2::AnyThread;
 use objc2::Message;
+use objc2::msg_send;
 use objc2::rc::Retained;
-use objc2::runtime::Bool;
+use objc2::runtime::{Bool, NSObjectProtocol, ProtocolObject};
+use objc2_core_graphics::CGColor;
+use objc2_core_location::CLLocation;
 use objc2_event_kit::{
-    EKAuthorizationStatus, EKCalendar, EKEntityType, EKEvent, EKEventStore, EKReminder, EKSpan,
+    EKAlarm, EKAlarmProximity, EKAuthorizationStatus, EKCalendar, EKCalendarEventAvailabilityMask,
+    EKCalendarItem, EKCalendarType, EKEntityMask, EKEntityType, EKErrorCode, EKErrorDomain,
+    EKEvent, EKEventAvailability, EKEventSearchCallback, EKEventStatus, EKEventStore,
+    EKEventStoreChangedNotification, EKParticipant, EKParticipantRole, EKParticipantStatus,
+    EKRecurrenceDayOfWeek, EKRecurrenceEnd, EKRecurrenceFrequency, EKRecurrenceRule, EKReminder,
+    EKSource, EKSourceType, EKSpan, EKStructuredLocation, EKWeekday,
+};
+use objc2_foundation::{
+    NSArray, NSCalendar, NSCocoaErrorDomain, NSDate, NSDateComponentUndefined, NSDateComponents,
+    NSError, NSNotification, NSNotificationCenter, NSNumber, NSPredicate, NSProcessInfo, NSRunLoop,
+    NSString, NSTimeZone, NSURL,
 };
-use objc2_foundation::{NSArray, NSDate, NSError, NSString};
+use std::cell::{Cell, RefCell};
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
 use std::sync::{Arc, Condvar, Mutex};
 use thiserror::Error;
 
@@ -80,6 +111,9 @@ pub enum EventKitError {
     #[error("Calendar not found: {0}")]
     CalendarNotFound(String),
 
+    #[error("'{query}' matches more than one calendar: {}", matches.join(", "))]
+    AmbiguousCalendar { query: String, matches: Vec<String> },
+
     #[error("Item not found: {0}")]
     ItemNotFound(String),
 
@@ -97,11 +131,176 @@ pub enum EventKitError {
 
     #[error("Invalid date range")]
     InvalidDateRange,
+
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+
+    #[error("Invalid input: {0}")]
+    InvalidInput(String),
+
+    #[error("Invalid URL: {0}")]
+    InvalidUrl(String),
+
+    #[error(
+        "Invalid location '{0}': latitude must be within ±90, longitude within ±180 and radius not negative"
+    )]
+    InvalidLocation(String),
+
+    #[error("Unknown time zone: {0}")]
+    InvalidTimeZone(String),
+
+    #[error("Invalid priority {0}: must be between 0 and 9")]
+    InvalidPriority(usize),
+
+    #[error("Calendar '{title}' can't hold {expected}")]
+    CalendarWrongType { title: String, expected: EntityType },
+
+    /// Carries the reminder's title
+    #[error("Reminder has no due date: {0}")]
+    NoDueDate(String),
+
+    #[error("Can't skip an occurrence: {0}")]
+    UnsupportedRecurrence(String),
+
+    #[error("Invalid RRULE: {0}")]
+    InvalidRRule(String),
+
+    #[error("Calendar source not found: {0}")]
+    SourceNotFound(String),
+
+    #[error("Source '{0}' doesn't allow adding calendars")]
+    SourceReadOnly(String),
+
+    #[cfg(feature = "regex")]
+    #[error("Invalid search pattern: {0}")]
+    InvalidPattern(String),
+
+    #[error("Calendar '{calendar}' can't show events as {availability}")]
+    AvailabilityUnsupported {
+        calendar: String,
+        availability: EventAvailability,
+    },
+
+    #[error("Timed out after {0:?} waiting for EventKit")]
+    Timeout(std::time::Duration),
 }
 
 /// Backward compatibility alias
 pub type RemindersError = EventKitError;
 
+/// The kind of item a calendar can hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EntityType {
+    /// Calendar events
+    Event,
+    /// Reminders
+    Reminder,
+}
+
+impl std::fmt::Display for EntityType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EntityType::Event => write!(f, "events"),
+            EntityType::Reminder => write!(f, "reminders"),
+        }
+    }
+}
+
+// Defines a typed wrapper around an EventKit identifier string
+macro_rules! identifier_type {
+    ($(#[$meta:meta])* $name:ident) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name(String);
+
+        impl $name {
+            /// Wraps an identifier string
+            pub fn new(id: impl Into<String>) -> Self {
+                Self(id.into())
+            }
+
+            /// The identifier as a string slice
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Unwraps the identifier string
+            pub fn into_string(self) -> String {
+                self.0
+            }
+        }
+
+        impl std::fmt::Display for $name {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl std::ops::Deref for $name {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl From<&str> for $name {
+            fn from(id: &str) -> Self {
+                Self(id.to_string())
+            }
+        }
+
+        impl From<String> for $name {
+            fn from(id: String) -> Self {
+                Self(id)
+            }
+        }
+
+        impl PartialEq<str> for $name {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $name {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+    };
+}
+
+identifier_type!(
+    /// Identifies a reminder, as in [`ReminderItem::identifier`]
+    ///
+    /// A distinct type from [`EventId`] and [`CalendarId`], so passing the
+    /// wrong kind of identifier fails to compile instead of coming back as
+    /// [`EventKitError::ItemNotFound`]. Build one from a string with `into()`.
+    ReminderId
+);
+
+identifier_type!(
+    /// Identifies an event, as in [`EventItem::identifier`]
+    ///
+    /// Every occurrence of a recurring event shares one `EventId`; see
+    /// [`EventOccurrence`] to address a single occurrence.
+    EventId
+);
+
+identifier_type!(
+    /// Identifies a calendar or reminder list, as in [`CalendarInfo::identifier`]
+    CalendarId
+);
+
+// Number of converted items buffered between EventKit and a streaming callback
+const STREAM_BUFFER_SIZE: usize = 64;
+
 /// Result type for EventKit operations
 pub type Result<T> = std::result::Result<T, EventKitError>;
 
@@ -109,42 +308,561 @@ pub type Result<T> = std::result::Result<T, EventKitError>;
 #[derive(Debug, Clone)]
 pub struct ReminderItem {
     /// Unique identifier for the reminder
-    pub identifier: String,
-    /// Title of the reminder
+    ///
+    /// Stable on this device, but it can change after a full sync. Use it for
+    /// short-lived references such as a follow-up update or delete.
+    pub identifier: ReminderId,
+    /// Identifier from the calendar server, if the reminder has been synced
+    ///
+    /// For reminders this still differs between devices, so it only helps
+    /// when the local identifier was reset by a re-sync.
+    pub external_identifier: Option<String>,
+    /// Title of the reminder (empty if the reminder has none)
     pub title: String,
     /// Optional notes/description
     pub notes: Option<String>,
     /// Whether the reminder is completed
     pub completed: bool,
-    /// Priority (0 = none, 1-4 = high, 5 = medium, 6-9 = low)
-    pub priority: usize,
+    /// When the reminder was completed, if it has been
+    pub completion_date: Option<DateTime<Local>>,
+    /// When the reminder was created, if EventKit knows
+    pub creation_date: Option<DateTime<Local>>,
+    /// When the reminder was last changed, if EventKit knows
+    pub last_modified_date: Option<DateTime<Local>>,
+    /// How important the reminder is
+    pub priority: Priority,
     /// Calendar/list the reminder belongs to
     pub calendar_title: Option<String>,
+    /// Identifier of the list the reminder belongs to
+    ///
+    /// Unlike the title this is unique, so prefer it for grouping reminders
+    /// or looking the list up again.
+    pub calendar_identifier: Option<CalendarId>,
+    /// URL attached to the reminder (e.g. a link back to a ticket)
+    pub url: Option<String>,
+    /// Alarms attached to the reminder
+    pub alarms: Vec<Alarm>,
+    /// When the reminder is due (midnight for date-only due dates)
+    pub due_date: Option<DateTime<Local>>,
+    /// How the reminder repeats; empty if it doesn't
+    pub recurrence_rules: Vec<RecurrenceRule>,
+}
+
+/// Criteria selecting reminders for bulk changes
+///
+/// Used by [`RemindersManager::complete_matching`] and
+/// [`RemindersManager::delete_matching`]. Every field that's set must match;
+/// the default filter matches every reminder.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReminderFilter {
+    /// Only reminders in lists with these titles
+    pub calendar_titles: Option<Vec<String>>,
+    /// Only reminders whose title or notes contain this text, ignoring case
+    pub text: Option<String>,
+    /// Only reminders due before this time; reminders without a due date
+    /// never match
+    pub due_before: Option<DateTime<Local>>,
+    /// Only completed (`Some(true)`) or incomplete (`Some(false)`) reminders
+    pub completed: Option<bool>,
+}
+
+impl ReminderFilter {
+    /// Whether a reminder passes this filter
+    pub fn matches(&self, reminder: &ReminderItem) -> bool {
+        let in_calendar = match (&self.calendar_titles, &reminder.calendar_title) {
+            (None, _) => true,
+            (Some(titles), Some(title)) => titles.contains(title),
+            (Some(_), None) => false,
+        };
+        let has_text = self.text.as_ref().is_none_or(|text| {
+            let text = text.to_lowercase();
+            reminder.title.to_lowercase().contains(&text)
+                || reminder
+                    .notes
+                    .as_ref()
+                    .is_some_and(|n| n.to_lowercase().contains(&text))
+        });
+        let due = self
+            .due_before
+            .is_none_or(|before| reminder.due_date.is_some_and(|due| due < before));
+        let completed = self.completed.is_none_or(|c| reminder.completed == c);
+
+        in_calendar && has_text && due && completed
+    }
+}
+
+/// A reminder fetch assembled step by step
+///
+/// ```no_run
+/// # use eventkit::{ReminderQuery, RemindersManager, SortOrder};
+/// # let manager = RemindersManager::new();
+/// let top = ReminderQuery::new()
+///     .lists(["Work"])
+///     .incomplete()
+///     .search("report")
+///     .sort_by(SortOrder::PriorityThenDue)
+///     .limit(10)
+///     .fetch(&manager)?;
+/// # Ok::<(), eventkit::EventKitError>(())
+/// ```
+///
+/// Without [`sort_by`](Self::sort_by), results come back in the same order
+/// as [`RemindersManager::fetch_reminders`].
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReminderQuery {
+    filter: ReminderFilter,
+    due_after: Option<DateTime<Local>>,
+    sort: Option<SortOrder>,
+    limit: Option<usize>,
+}
+
+impl ReminderQuery {
+    /// Creates a query matching every reminder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only reminders in lists with these titles
+    pub fn lists<I, S>(mut self, titles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.filter.calendar_titles = Some(titles.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only incomplete reminders
+    pub fn incomplete(mut self) -> Self {
+        self.filter.completed = Some(false);
+        self
+    }
+
+    /// Only completed reminders
+    pub fn completed(mut self) -> Self {
+        self.filter.completed = Some(true);
+        self
+    }
+
+    /// Only reminders due at or after `start` and before `end`; reminders
+    /// without a due date never match
+    pub fn due_between(mut self, start: DateTime<Local>, end: DateTime<Local>) -> Self {
+        self.due_after = Some(start);
+        self.filter.due_before = Some(end);
+        self
+    }
+
+    /// Only reminders whose title or notes contain `text`, ignoring case
+    pub fn search(mut self, text: impl Into<String>) -> Self {
+        self.filter.text = Some(text.into());
+        self
+    }
+
+    /// Sorts the results in this order
+    pub fn sort_by(mut self, sort: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Keeps at most `limit` reminders, after sorting
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Whether a reminder passes this query's criteria
+    pub fn matches(&self, reminder: &ReminderItem) -> bool {
+        self.filter.matches(reminder)
+            && self
+                .due_after
+                .is_none_or(|after| reminder.due_date.is_some_and(|due| due >= after))
+    }
+
+    /// Runs the query (blocking)
+    ///
+    /// Returns [`EventKitError::InvalidDateRange`] if the
+    /// [`due_between`](Self::due_between) window is empty.
+    pub fn fetch(&self, manager: &RemindersManager) -> Result<Vec<ReminderItem>> {
+        manager.fetch_query(self)
+    }
+}
+
+/// A reminder to create with [`RemindersManager::create_reminders`]
+///
+/// The fields match [`RemindersManager::create_reminder`]'s arguments;
+/// anything left at its default is unset.
+#[derive(Debug, Clone, Default)]
+pub struct NewReminder {
+    /// Title of the reminder
+    pub title: String,
+    /// Optional notes/description
+    pub notes: Option<String>,
+    /// List to add it to; `None` uses the default list
+    pub calendar_title: Option<String>,
+    /// How important the reminder is
+    pub priority: Option<Priority>,
+    /// Alarms to attach
+    pub alarms: Vec<Alarm>,
+    /// When the reminder is due
+    pub due_date: Option<DateTime<Local>>,
+    /// URL to attach
+    pub url: Option<String>,
+}
+
+impl NewReminder {
+    /// A reminder with just a title, in the default list
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+}
+
+/// A reminder's priority
+///
+/// EventKit stores priority as a number from 0 to 9: 0 is none, 1-4 high,
+/// 5 medium and 6-9 low. Reminders itself only uses 1, 5 and 9, which map
+/// to `High`, `Medium` and `Low`; any other value is kept as `Raw` so it
+/// round-trips unchanged. Priorities compare by that number, so
+/// `Priority::Raw(5) == Priority::Medium`.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum Priority {
+    /// No priority (0)
+    #[default]
+    None,
+    /// High priority (1)
+    High,
+    /// Medium priority (5)
+    Medium,
+    /// Low priority (9)
+    Low,
+    /// Any other EventKit value; only 0-9 can be saved
+    Raw(u8),
+}
+
+impl Priority {
+    /// The EventKit value, 0-9 for any priority that can be saved
+    pub fn value(self) -> u8 {
+        match self {
+            Priority::None => 0,
+            Priority::High => 1,
+            Priority::Medium => 5,
+            Priority::Low => 9,
+            Priority::Raw(value) => value,
+        }
+    }
+
+    /// The band the value falls in: `High` for 1-4, `Medium` for 5, `Low`
+    /// for 6-9 and `None` for 0
+    ///
+    /// Out-of-range values stay `Raw`.
+    pub fn level(self) -> Priority {
+        match self.value() {
+            0 => Priority::None,
+            1..=4 => Priority::High,
+            5 => Priority::Medium,
+            6..=9 => Priority::Low,
+            value => Priority::Raw(value),
+        }
+    }
+}
+
+impl From<u8> for Priority {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => Priority::None,
+            1 => Priority::High,
+            5 => Priority::Medium,
+            9 => Priority::Low,
+            value => Priority::Raw(value),
+        }
+    }
+}
+
+impl From<Priority> for u8 {
+    fn from(priority: Priority) -> Self {
+        priority.value()
+    }
+}
+
+impl PartialEq for Priority {
+    fn eq(&self, other: &Self) -> bool {
+        self.value() == other.value()
+    }
+}
+
+impl Eq for Priority {}
+
+impl std::hash::Hash for Priority {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.value().hash(state);
+    }
+}
+
+impl std::fmt::Display for Priority {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Priority::None => write!(f, "none"),
+            Priority::High => write!(f, "high"),
+            Priority::Medium => write!(f, "medium"),
+            Priority::Low => write!(f, "low"),
+            Priority::Raw(value) => write!(f, "{}", value),
+        }
+    }
 }
 
 /// Represents a calendar (reminder list)
 #[derive(Debug, Clone)]
 pub struct CalendarInfo {
     /// Unique identifier
-    pub identifier: String,
+    pub identifier: CalendarId,
     /// Title of the calendar
     pub title: String,
     /// Source name (e.g., iCloud, Local)
     pub source: Option<String>,
     /// Whether content can be modified
     pub allows_modifications: bool,
+    /// Kind of calendar (local, CalDAV, subscription, ...)
+    pub calendar_type: CalendarType,
+    /// Display color of the calendar, if it has one
+    pub color: Option<CalendarColor>,
+}
+
+/// An sRGB calendar color
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct CalendarColor {
+    /// Red channel
+    pub red: u8,
+    /// Green channel
+    pub green: u8,
+    /// Blue channel
+    pub blue: u8,
+}
+
+impl std::fmt::Display for CalendarColor {
+    /// Formats the color as `#RRGGBB`
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.red, self.green, self.blue)
+    }
+}
+
+impl CalendarInfo {
+    /// Whether this is a read-only subscribed calendar feed
+    pub fn is_subscription(&self) -> bool {
+        self.calendar_type == CalendarType::Subscription
+    }
+
+    /// Whether this is the built-in Birthdays calendar
+    pub fn is_birthday(&self) -> bool {
+        self.calendar_type == CalendarType::Birthday
+    }
+}
+
+/// The kind of a calendar, as reported by EventKit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalendarType {
+    /// Stored only on this Mac
+    Local,
+    /// Synced over CalDAV (including iCloud)
+    CalDav,
+    /// Synced with an Exchange server
+    Exchange,
+    /// A subscribed, read-only calendar feed
+    Subscription,
+    /// The built-in Birthdays calendar
+    Birthday,
+}
+
+impl From<EKCalendarType> for CalendarType {
+    fn from(calendar_type: EKCalendarType) -> Self {
+        if calendar_type == EKCalendarType::CalDAV {
+            CalendarType::CalDav
+        } else if calendar_type == EKCalendarType::Exchange {
+            CalendarType::Exchange
+        } else if calendar_type == EKCalendarType::Subscription {
+            CalendarType::Subscription
+        } else if calendar_type == EKCalendarType::Birthday {
+            CalendarType::Birthday
+        } else {
+            CalendarType::Local
+        }
+    }
+}
+
+impl std::fmt::Display for CalendarType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarType::Local => write!(f, "Local"),
+            CalendarType::CalDav => write!(f, "CalDAV"),
+            CalendarType::Exchange => write!(f, "Exchange"),
+            CalendarType::Subscription => write!(f, "Subscription"),
+            CalendarType::Birthday => write!(f, "Birthday"),
+        }
+    }
+}
+
+/// The account a new calendar is created in, for
+/// [`EventsManager::create_calendar`]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CalendarSource {
+    /// The account holding the default calendar for new events
+    #[default]
+    Default,
+    /// The iCloud account
+    ICloud,
+    /// "On My Mac", stored only on this machine
+    Local,
+    /// The account with this title (ignoring case), e.g. an Exchange account
+    Named(String),
+}
+
+impl std::fmt::Display for CalendarSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            CalendarSource::Default => write!(f, "default"),
+            CalendarSource::ICloud => write!(f, "iCloud"),
+            CalendarSource::Local => write!(f, "On My Mac"),
+            CalendarSource::Named(title) => write!(f, "{}", title),
+        }
+    }
 }
 
 /// The main reminders manager providing access to EventKit functionality
+///
+/// Each manager keeps its own cache of reminder list handles, so lookups by
+/// identifier or title don't rescan every calendar. The cache is
+/// per-manager-instance and is invalidated whenever the underlying event
+/// store reports a change.
+///
+/// Subtasks created in the Reminders app come back as ordinary, top-level
+/// reminders: EventKit exposes no public parent relationship to read or set.
 pub struct RemindersManager {
     store: Retained<EKEventStore>,
+    calendars: CalendarCache,
+    auto_refresh: bool,
+    retry: RetryPolicy,
+    dry_run: bool,
+    fuzzy_titles: bool,
+    timeout: std::time::Duration,
 }
 
 impl RemindersManager {
     /// Creates a new RemindersManager instance
     pub fn new() -> Self {
-        let store = unsafe { EKEventStore::new() };
-        Self { store }
+        Self::with_store(unsafe { EKEventStore::new() })
+    }
+
+    /// Creates a manager on an existing `EKEventStore`
+    ///
+    /// Lets code that talks to EventKit through objc2 directly share one store
+    /// with this crate. Requires the `raw-store` feature; like
+    /// [`raw_store`](Self::raw_store), this is an escape hatch with no
+    /// stability guarantees.
+    #[cfg(feature = "raw-store")]
+    pub fn from_store(store: Retained<EKEventStore>) -> Self {
+        Self::with_store(store)
+    }
+
+    /// Borrows the underlying `EKEventStore`
+    ///
+    /// An escape hatch for reading properties this crate doesn't wrap yet.
+    /// Requires the `raw-store` feature and carries no stability guarantees:
+    /// it may change or go away whenever the wrappers catch up.
+    ///
+    /// # Safety
+    ///
+    /// The manager assumes it is the only thing changing the store. Saving,
+    /// removing, committing or resetting through the returned store bypasses
+    /// dry-run mode and the retry policy, and can leave this manager's cached
+    /// calendars out of date until the next change notification.
+    #[cfg(feature = "raw-store")]
+    pub unsafe fn raw_store(&self) -> &EKEventStore {
+        &self.store
+    }
+
+    // Helper to create a manager on an existing (possibly shared) store
+    fn with_store(store: Retained<EKEventStore>) -> Self {
+        let calendars = CalendarCache::new(&store, EKEntityType::Reminder);
+        Self {
+            store,
+            calendars,
+            auto_refresh: false,
+            retry: RetryPolicy::default(),
+            dry_run: false,
+            fuzzy_titles: false,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Asks EventKit to sync remote (iCloud/CalDAV) sources if they're stale
+    ///
+    /// This only starts the sync: changes made on other devices may still
+    /// arrive after it returns, so a fetch made right away can miss them.
+    pub fn refresh(&self) {
+        unsafe { self.store.refreshSourcesIfNecessary() };
+    }
+
+    /// Makes every fetch call [`refresh`](Self::refresh) first
+    ///
+    /// Off by default. Turning it on narrows the window for stale results at
+    /// the cost of extra latency on each fetch while sources are checked.
+    pub fn set_auto_refresh(&mut self, enabled: bool) {
+        self.auto_refresh = enabled;
+    }
+
+    /// Sets how saves are retried when they fail with a transient error
+    ///
+    /// Defaults to no retries. See [`RetryPolicy`] for which errors count as
+    /// transient.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    /// Validates changes without saving them
+    ///
+    /// Off by default. While on, creating, updating and deleting reminders still
+    /// looks up calendars and checks the input, returning the same errors,
+    /// but nothing is written to the store. The returned [`ReminderItem`] shows
+    /// what would have been saved; a newly created one has an empty
+    /// `identifier`, since it was never persisted.
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.dry_run = enabled;
+    }
+
+    /// Lets calendar titles match loosely when there's no exact match
+    ///
+    /// Off by default. While on, a title given to create or update calls
+    /// and [`get_calendar_by_title`](Self::get_calendar_by_title) that
+    /// doesn't match exactly is matched ignoring case, then as a prefix
+    /// ("work" finds "Work Projects"). An exact match always wins. If the
+    /// loose match finds several calendars,
+    /// [`EventKitError::AmbiguousCalendar`] lists them so the caller can ask
+    /// for something more specific.
+    pub fn set_fuzzy_calendar_titles(&mut self, enabled: bool) {
+        self.fuzzy_titles = enabled;
+    }
+
+    /// Sets how long blocking calls wait for EventKit to answer
+    ///
+    /// Defaults to 30 seconds. Fetches and [`request_access`](Self::request_access)
+    /// wait on a completion handler that EventKit can fail to call when its
+    /// daemon is unresponsive; after this long they give up with
+    /// [`EventKitError::Timeout`] instead of blocking forever. A permission
+    /// prompt counts against the timeout, so allow for the user reading it.
+    pub fn set_timeout(&mut self, timeout: std::time::Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Calls `callback` whenever the event store reports a change
+    ///
+    /// Changes include edits made in other apps and items synced from other
+    /// devices. The notification doesn't say what changed, so re-fetch
+    /// whatever you're showing. The callback may run on any thread, and
+    /// command-line tools only receive it while a run loop is running (see
+    /// [`run_loop_for`]). Drop the returned subscription to stop listening.
+    pub fn on_change(&self, callback: impl Fn() + Send + Sync + 'static) -> ChangeSubscription {
+        ChangeSubscription::new(&self.store, callback)
     }
 
     /// Gets the current authorization status for reminders
@@ -156,8 +874,11 @@ impl RemindersManager {
 
     /// Requests full access to reminders (blocking)
     ///
-    /// Returns Ok(true) if access was granted, Ok(false) if denied
-    pub fn request_access(&self) -> Result<bool> {
+    /// Returns whether access was granted along with the authorization status
+    /// read back afterwards, so a refusal can be told apart: `Denied` when
+    /// the user declined, `Restricted` when a device policy (such as MDM)
+    /// blocks access.
+    pub fn request_access(&self) -> Result<AccessResult> {
         let result = Arc::new((Mutex::new(None::<(bool, Option<String>)>), Condvar::new()));
         let result_clone = Arc::clone(&result);
 
@@ -178,42 +899,50 @@ impl RemindersManager {
         unsafe {
             // Convert RcBlock to raw pointer for the API
             let block_ptr = &*completion as *const _ as *mut _;
-            self.store
-                .requestFullAccessToRemindersWithCompletion(block_ptr);
-        }
-
-        let (lock, cvar) = &*result;
-        let mut res = lock.lock().unwrap();
-        while res.is_none() {
-            res = cvar.wait(res).unwrap();
+            if supports_full_access() {
+                self.store
+                    .requestFullAccessToRemindersWithCompletion(block_ptr);
+            } else {
+                #[allow(deprecated)]
+                self.store
+                    .requestAccessToEntityType_completion(EKEntityType::Reminder, block_ptr);
+            }
         }
 
-        match res.take() {
-            Some((granted, None)) => Ok(granted),
-            Some((_, Some(error))) => Err(RemindersError::AuthorizationRequestFailed(error)),
-            None => Err(RemindersError::AuthorizationRequestFailed(
-                "Unknown error".to_string(),
-            )),
+        match wait_for_completion(&result, self.timeout)? {
+            (granted, None) => Ok(AccessResult {
+                granted,
+                status: Self::authorization_status(),
+            }),
+            (_, Some(error)) => Err(RemindersError::AuthorizationRequestFailed(error)),
         }
     }
 
     /// Ensures we have authorization, requesting if needed
+    ///
+    /// Shows the system permission prompt when the user hasn't decided yet;
+    /// use [`check_authorization`](Self::check_authorization) to avoid that.
     pub fn ensure_authorized(&self) -> Result<()> {
-        match Self::authorization_status() {
-            AuthorizationStatus::FullAccess => Ok(()),
-            AuthorizationStatus::NotDetermined => {
-                if self.request_access()? {
-                    Ok(())
-                } else {
-                    Err(RemindersError::AuthorizationDenied)
-                }
-            }
-            AuthorizationStatus::Denied => Err(RemindersError::AuthorizationDenied),
-            AuthorizationStatus::Restricted => Err(RemindersError::AuthorizationRestricted),
-            AuthorizationStatus::WriteOnly => Ok(()), // Can still read with write-only in some cases
+        match self.check_authorization() {
+            Err(RemindersError::AuthorizationNotDetermined) => self.request_access()?.into_result(),
+            other => other,
         }
     }
 
+    /// Checks authorization without ever showing the system prompt
+    ///
+    /// Returns [`EventKitError::AuthorizationNotDetermined`] if the user
+    /// hasn't been asked yet, leaving it to the caller to decide when to
+    /// call [`request_access`](Self::request_access).
+    pub fn check_authorization(&self) -> Result<()> {
+        authorization_result(Self::authorization_status())
+    }
+
+    /// Whether access is currently granted, without ever prompting
+    pub fn is_authorized(&self) -> bool {
+        self.check_authorization().is_ok()
+    }
+
     /// Lists all reminder calendars (lists)
     pub fn list_calendars(&self) -> Result<Vec<CalendarInfo>> {
         self.ensure_authorized()?;
@@ -228,68 +957,344 @@ impl RemindersManager {
         Ok(result)
     }
 
+    /// Lists only the reminder lists new reminders can be saved into
+    ///
+    /// Leaves out read-only calendars such as Birthdays and subscribed
+    /// feeds, and any calendar that can't hold reminders, so a picker built from
+    /// this never offers a target that would reject the save.
+    pub fn list_writable_calendars(&self) -> Result<Vec<CalendarInfo>> {
+        self.ensure_authorized()?;
+
+        let calendars = unsafe { self.store.calendarsForEntityType(EKEntityType::Reminder) };
+
+        Ok(calendars
+            .iter()
+            .filter(|calendar| is_writable(calendar, EntityType::Reminder))
+            .map(|calendar| calendar_to_info(&calendar))
+            .collect())
+    }
+
     /// Gets the default calendar for new reminders
+    ///
+    /// Served from this manager's calendar cache. The user can change the
+    /// default at any time in Reminders' settings; the cache picks that up
+    /// after the next store change notification or [`reset`](Self::reset).
     pub fn default_calendar(&self) -> Result<CalendarInfo> {
         self.ensure_authorized()?;
 
-        let calendar = unsafe { self.store.defaultCalendarForNewReminders() };
-
-        match calendar {
+        match self.calendars.default() {
             Some(cal) => Ok(calendar_to_info(&cal)),
             None => Err(RemindersError::NoDefaultCalendar),
         }
     }
 
+    /// Whether the calendar with this identifier is the default for new reminders
+    ///
+    /// Returns `false` if there is no default calendar or access hasn't been
+    /// granted.
+    pub fn is_default_calendar(&self, identifier: &CalendarId) -> bool {
+        self.check_authorization().is_ok()
+            && self.calendars.default().is_some_and(|cal| {
+                unsafe { cal.calendarIdentifier() }.to_string() == identifier.as_str()
+            })
+    }
+
+    /// Gets a reminder list by its identifier
+    ///
+    /// Served from this manager's calendar cache after the first lookup.
+    pub fn get_calendar_by_id(&self, identifier: &CalendarId) -> Result<CalendarInfo> {
+        self.ensure_authorized()?;
+        self.calendars
+            .by_id(identifier)
+            .map(|cal| calendar_to_info(&cal))
+            .ok_or_else(|| RemindersError::CalendarNotFound(identifier.to_string()))
+    }
+
+    /// Gets a reminder list by its title
+    ///
+    /// Titles aren't unique; the first matching list is returned.
+    pub fn get_calendar_by_title(&self, title: &str) -> Result<CalendarInfo> {
+        self.ensure_authorized()?;
+        let calendar = self.find_calendar_by_title(title)?;
+        Ok(calendar_to_info(&calendar))
+    }
+
     /// Fetches all reminders (blocking)
     pub fn fetch_all_reminders(&self) -> Result<Vec<ReminderItem>> {
         self.fetch_reminders(None)
     }
 
     /// Fetches reminders from specific calendars (blocking)
+    ///
+    /// Incomplete reminders come first, then reminders are ordered by due
+    /// date (undated last) and priority. Use [`SortOrder::sort_reminders`]
+    /// to re-sort. Each reminder appears once, even if several of the given
+    /// titles name the same list.
     pub fn fetch_reminders(&self, calendar_titles: Option<&[&str]>) -> Result<Vec<ReminderItem>> {
         self.ensure_authorized()?;
+        self.refresh_if_enabled();
 
-        let calendars: Option<Retained<NSArray<EKCalendar>>> = match calendar_titles {
-            Some(titles) => {
-                let all_calendars =
-                    unsafe { self.store.calendarsForEntityType(EKEntityType::Reminder) };
-                let mut matching: Vec<Retained<EKCalendar>> = Vec::new();
-
-                for cal in all_calendars.iter() {
-                    let title = unsafe { cal.title() };
-                    let title_str = title.to_string();
-                    if titles.iter().any(|t| *t == title_str) {
-                        matching.push(cal.retain());
-                    }
-                }
+        let predicate = self.reminders_predicate(calendar_titles)?;
 
-                if matching.is_empty() {
-                    return Err(RemindersError::CalendarNotFound(titles.join(", ")));
-                }
+        let mut reminders = self.fetch_matching(&predicate)?;
+        sort_reminders_default(&mut reminders);
+        Ok(reminders)
+    }
 
-                Some(NSArray::from_retained_slice(&matching))
-            }
-            None => None,
-        };
+    /// Fetches reminders from specific calendars in the given order, keeping
+    /// at most `limit` of them (blocking)
+    ///
+    /// For "top 10 by due date" style views, this avoids fully sorting
+    /// every reminder only to throw most of them away. With
+    /// [`SortOrder::None`], the first `limit` reminders EventKit returned
+    /// are kept.
+    pub fn fetch_reminders_sorted(
+        &self,
+        calendar_titles: Option<&[&str]>,
+        sort: SortOrder,
+        limit: Option<usize>,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let predicate = self.reminders_predicate(calendar_titles)?;
+
+        let mut reminders = self.fetch_matching(&predicate)?;
+        match limit {
+            Some(limit) => sort.sort_reminders_limited(&mut reminders, limit),
+            None => sort.sort_reminders(&mut reminders),
+        }
+        Ok(reminders)
+    }
+
+    /// Calls `f` for each reminder in the given calendars as it is fetched (blocking)
+    ///
+    /// Unlike [`fetch_reminders`](Self::fetch_reminders), this never holds the
+    /// full result set in memory: reminders are converted one at a time and
+    /// handed over through a small bounded buffer, so `f` starts running
+    /// before the fetch has finished. `f` runs on the calling thread and is
+    /// never called after this method returns. If `f` panics, the fetch is
+    /// abandoned and the panic propagates to the caller.
+    pub fn for_each_reminder(
+        &self,
+        calendar_titles: Option<&[&str]>,
+        mut f: impl FnMut(ReminderItem),
+    ) -> Result<()> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let predicate = self.reminders_predicate(calendar_titles)?;
+
+        let (tx, rx) = mpsc::sync_channel::<ReminderItem>(STREAM_BUFFER_SIZE);
+
+        let completion = RcBlock::new(move |reminders: *mut NSArray<EKReminder>| {
+            if reminders.is_null() {
+                return;
+            }
+            let reminders = unsafe { Retained::retain(reminders).unwrap() };
+            for reminder in reminders.iter() {
+                // The receiver is gone if the caller's callback panicked
+                if tx.send(reminder_to_item(&reminder)).is_err() {
+                    break;
+                }
+            }
+        });
+
+        unsafe {
+            self.store
+                .fetchRemindersMatchingPredicate_completion(&predicate, &completion);
+        }
+        // EventKit holds its own copy of the block; dropping ours means the
+        // channel closes as soon as the completion handler finishes
+        drop(completion);
+
+        drain_channel(&rx, self.timeout, &mut f)
+    }
+
+    /// Fetches reminders changed after `since`, oldest change first (blocking)
+    ///
+    /// Meant for incremental exports and sync: remember the newest
+    /// [`last_modified_date`](ReminderItem::last_modified_date) seen and pass
+    /// it next time. Only reminders that changed are converted. Reminders
+    /// without a modification date are always included, since there's no
+    /// telling when they changed. Deleted reminders can't be reported.
+    pub fn fetch_changed_since(
+        &self,
+        since: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let predicate = self.reminders_predicate(calendar_titles)?;
+        let mut reminders = self.fetch_matching_where(&predicate, move |reminder| {
+            let modified = unsafe { reminder.lastModifiedDate() };
+            changed_after(modified.map(|d| nsdate_to_datetime(&d)), since)
+        })?;
+        reminders.sort_by_key(|r| r.last_modified_date);
+        Ok(reminders)
+    }
+
+    /// Fetches incomplete reminders, ordered by due date (undated last) and priority
+    pub fn fetch_incomplete_reminders(&self) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let predicate = self.incomplete_reminders_predicate();
+
+        let mut reminders = self.fetch_matching(&predicate)?;
+        sort_reminders_default(&mut reminders);
+        Ok(reminders)
+    }
+
+    /// Fetches incomplete reminders due within a window, optionally limited
+    /// to the given list titles (blocking)
+    ///
+    /// EventKit does the filtering, so only matching reminders are converted.
+    /// Either end of the window may be open: `(None, Some(friday))` is
+    /// everything due by Friday, including overdue items. Giving either bound
+    /// leaves out reminders with no due date; with neither, this is
+    /// [`fetch_incomplete_reminders`](Self::fetch_incomplete_reminders)
+    /// scoped to the lists. Returns [`EventKitError::InvalidDateRange`] if
+    /// `due_start` isn't before `due_end`. Sorted like
+    /// `fetch_incomplete_reminders`.
+    pub fn fetch_incomplete_reminders_due(
+        &self,
+        due_start: Option<DateTime<Local>>,
+        due_end: Option<DateTime<Local>>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        if let (Some(start), Some(end)) = (due_start, due_end)
+            && start >= end
+        {
+            return Err(EventKitError::InvalidDateRange);
+        }
 
+        let calendars = self.calendars_matching(calendar_titles)?;
+        let start_date = due_start.map(datetime_to_nsdate);
+        let end_date = due_end.map(datetime_to_nsdate);
         let predicate = unsafe {
             self.store
-                .predicateForRemindersInCalendars(calendars.as_deref())
+                .predicateForIncompleteRemindersWithDueDateStarting_ending_calendars(
+                    start_date.as_deref(),
+                    end_date.as_deref(),
+                    calendars.as_deref(),
+                )
         };
 
-        let result = Arc::new((Mutex::new(None::<Vec<ReminderItem>>), Condvar::new()));
+        let mut reminders = self.fetch_matching(&predicate)?;
+        sort_reminders_default(&mut reminders);
+        Ok(reminders)
+    }
+
+    /// Fetches reminders from one list, identified by its identifier (blocking)
+    ///
+    /// List titles aren't unique, so this is the reliable way to scope a
+    /// query to a single list. Returns [`EventKitError::CalendarNotFound`] if
+    /// no reminder list has this identifier, including when it belongs to an
+    /// event calendar. Sorted like [`fetch_reminders`](Self::fetch_reminders).
+    pub fn fetch_reminders_in_calendar(
+        &self,
+        calendar_id: &CalendarId,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let calendar = self
+            .calendars
+            .by_id(calendar_id)
+            .ok_or_else(|| EventKitError::CalendarNotFound(calendar_id.to_string()))?;
+        let calendars = NSArray::from_retained_slice(&[calendar]);
+        let predicate = unsafe {
+            self.store
+                .predicateForRemindersInCalendars(Some(&calendars))
+        };
+
+        let mut reminders = self.fetch_matching(&predicate)?;
+        sort_reminders_default(&mut reminders);
+        Ok(reminders)
+    }
+
+    /// Fetches reminders completed within a date range (blocking)
+    ///
+    /// Useful for reports such as "everything finished last week". Results
+    /// are sorted by completion date, most recent first. `start` must be
+    /// before `end`, otherwise [`EventKitError::InvalidDateRange`] is returned.
+    pub fn fetch_completed_reminders(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        if start >= end {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let calendars = self.calendars_matching(calendar_titles)?;
+        let start_date = datetime_to_nsdate(start);
+        let end_date = datetime_to_nsdate(end);
+        let predicate = unsafe {
+            self.store
+                .predicateForCompletedRemindersWithCompletionDateStarting_ending_calendars(
+                    Some(&start_date),
+                    Some(&end_date),
+                    calendars.as_deref(),
+                )
+        };
+
+        let mut reminders = self.fetch_matching(&predicate)?;
+        reminders.sort_by_key(|r| std::cmp::Reverse(r.completion_date));
+        Ok(reminders)
+    }
+
+    /// Gets the incomplete reminder with the nearest future due date
+    ///
+    /// Only reminders due after now are considered, so overdue reminders and
+    /// reminders without a due date are skipped. Returns `None` when nothing
+    /// is due.
+    pub fn next_due_reminder(&self) -> Result<Option<ReminderItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let now = Local::now();
+        let start = datetime_to_nsdate(now);
+        let predicate = unsafe {
+            self.store
+                .predicateForIncompleteRemindersWithDueDateStarting_ending_calendars(
+                    Some(&start),
+                    None,
+                    None,
+                )
+        };
+
+        let result = Arc::new((Mutex::new(None::<Option<ReminderItem>>), Condvar::new()));
         let result_clone = Arc::clone(&result);
 
         let completion = RcBlock::new(move |reminders: *mut NSArray<EKReminder>| {
-            let items = if reminders.is_null() {
-                Vec::new()
+            // Only convert the winning reminder
+            let next = if reminders.is_null() {
+                None
             } else {
                 let reminders = unsafe { Retained::retain(reminders).unwrap() };
-                reminders.iter().map(|r| reminder_to_item(&r)).collect()
+                reminders
+                    .iter()
+                    .filter_map(|r| {
+                        let due = unsafe { r.dueDateComponents() }
+                            .and_then(|c| components_to_datetime(&c))?;
+                        (due >= now).then_some((due, r))
+                    })
+                    .min_by_key(|(due, _)| *due)
+                    .map(|(_, r)| reminder_to_item(&r))
             };
             let (lock, cvar) = &*result_clone;
             let mut guard = lock.lock().unwrap();
-            *guard = Some(items);
+            *guard = Some(next);
             cvar.notify_one();
         });
 
@@ -298,41 +1303,59 @@ impl RemindersManager {
                 .fetchRemindersMatchingPredicate_completion(&predicate, &completion);
         }
 
-        let (lock, cvar) = &*result;
-        let mut guard = lock.lock().unwrap();
-        while guard.is_none() {
-            guard = cvar.wait(guard).unwrap();
-        }
-
-        guard
-            .take()
-            .ok_or_else(|| RemindersError::FetchFailed("Unknown error".to_string()))
+        wait_for_completion(&result, self.timeout)
     }
 
-    /// Fetches incomplete reminders
-    pub fn fetch_incomplete_reminders(&self) -> Result<Vec<ReminderItem>> {
+    /// Fetches incomplete reminders that are past due, most overdue first
+    ///
+    /// A reminder is overdue once its due time has passed. One due on a date
+    /// with no time only becomes overdue after that whole day is over, so a
+    /// reminder due today isn't overdue until tomorrow. EventKit narrows the
+    /// fetch to reminders due before now; the date-only rule is applied
+    /// afterwards.
+    pub fn fetch_overdue_reminders(&self) -> Result<Vec<ReminderItem>> {
         self.ensure_authorized()?;
+        self.refresh_if_enabled();
 
+        let now = Local::now();
+        let end = datetime_to_nsdate(now);
         let predicate = unsafe {
             self.store
                 .predicateForIncompleteRemindersWithDueDateStarting_ending_calendars(
-                    None, None, None,
+                    None,
+                    Some(&end),
+                    None,
                 )
         };
 
-        let result = Arc::new((Mutex::new(None::<Vec<ReminderItem>>), Condvar::new()));
+        let mut reminders = self.fetch_matching_where(&predicate, move |reminder| {
+            unsafe { reminder.dueDateComponents() }
+                .and_then(|c| overdue_from(&c))
+                .is_some_and(|deadline| deadline < now)
+        })?;
+        reminders.sort_by_key(|r| r.due_date);
+        Ok(reminders)
+    }
+
+    /// Counts incomplete reminders without converting them to [`ReminderItem`]s
+    pub fn count_incomplete_reminders(&self) -> Result<usize> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let predicate = self.incomplete_reminders_predicate();
+
+        let result = Arc::new((Mutex::new(None::<usize>), Condvar::new()));
         let result_clone = Arc::clone(&result);
 
         let completion = RcBlock::new(move |reminders: *mut NSArray<EKReminder>| {
-            let items = if reminders.is_null() {
-                Vec::new()
+            let count = if reminders.is_null() {
+                0
             } else {
-                let reminders = unsafe { Retained::retain(reminders).unwrap() };
-                reminders.iter().map(|r| reminder_to_item(&r)).collect()
+                unsafe { &*reminders }.count()
             };
             let (lock, cvar) = &*result_clone;
             let mut guard = lock.lock().unwrap();
-            *guard = Some(items);
+            *guard = Some(count);
             cvar.notify_one();
         });
 
@@ -341,75 +1364,169 @@ impl RemindersManager {
                 .fetchRemindersMatchingPredicate_completion(&predicate, &completion);
         }
 
-        let (lock, cvar) = &*result;
-        let mut guard = lock.lock().unwrap();
-        while guard.is_none() {
-            guard = cvar.wait(guard).unwrap();
-        }
-
-        guard
-            .take()
-            .ok_or_else(|| RemindersError::FetchFailed("Unknown error".to_string()))
+        wait_for_completion(&result, self.timeout)
     }
 
     /// Creates a new reminder
+    ///
+    /// `priority` must be 0-9, otherwise [`EventKitError::InvalidPriority`]
+    /// is returned and nothing is saved. Relative alarms fire relative to the
+    /// due date, so without a `due_date` they're rejected with
+    /// [`EventKitError::NoDueDate`]. A `url` must be absolute, otherwise
+    /// [`EventKitError::InvalidUrl`] is returned.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_reminder(
         &self,
         title: &str,
         notes: Option<&str>,
         calendar_title: Option<&str>,
-        priority: Option<usize>,
+        priority: Option<Priority>,
+        alarms: &[Alarm],
+        due_date: Option<DateTime<Local>>,
+        url: Option<&str>,
     ) -> Result<ReminderItem> {
         self.ensure_authorized()?;
+        check_reminder_alarms(alarms, due_date.is_some(), title)?;
+
+        let reminder = self.build_reminder(
+            title,
+            notes,
+            calendar_title,
+            priority,
+            alarms,
+            due_date,
+            url,
+        )?;
 
-        let reminder = unsafe { EKReminder::reminderWithEventStore(&self.store) };
+        // Save
+        self.finish_reminder(&reminder, true)
+    }
 
-        // Set title
-        let ns_title = NSString::from_str(title);
-        unsafe { reminder.setTitle(Some(&ns_title)) };
+    /// Creates a new reminder without committing it
+    ///
+    /// The reminder is staged in this manager's event store and only written
+    /// when [`commit`](Self::commit) is called; [`reset`](Self::reset)
+    /// abandons it. Its identifier may not be final until committed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_reminder_deferred(
+        &self,
+        title: &str,
+        notes: Option<&str>,
+        calendar_title: Option<&str>,
+        priority: Option<Priority>,
+        alarms: &[Alarm],
+        due_date: Option<DateTime<Local>>,
+        url: Option<&str>,
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
+        check_reminder_alarms(alarms, due_date.is_some(), title)?;
+
+        let reminder = self.build_reminder(
+            title,
+            notes,
+            calendar_title,
+            priority,
+            alarms,
+            due_date,
+            url,
+        )?;
+
+        self.finish_reminder(&reminder, false)
+    }
 
-        // Set notes if provided
-        if let Some(notes_text) = notes {
-            let ns_notes = NSString::from_str(notes_text);
-            unsafe { reminder.setNotes(Some(&ns_notes)) };
-        }
+    /// Creates several reminders with a single commit
+    ///
+    /// Much faster than calling [`create_reminder`](Self::create_reminder)
+    /// in a loop, and synced accounts see one change instead of one per
+    /// reminder. Each reminder is checked and staged on its own: one that's
+    /// invalid or can't be saved is reported in the returned list, in input
+    /// order, without stopping the rest. An error is only returned for the
+    /// call as a whole when access is denied or the final commit fails; the
+    /// staged reminders can then be retried with [`commit`](Self::commit) or
+    /// dropped with [`reset`](Self::reset).
+    pub fn create_reminders(&self, reminders: &[NewReminder]) -> Result<Vec<Result<ReminderItem>>> {
+        self.ensure_authorized()?;
 
-        // Set priority if provided
-        if let Some(p) = priority {
-            unsafe { reminder.setPriority(p) };
+        let staged: Vec<Result<Retained<EKReminder>>> = reminders
+            .iter()
+            .map(|new| {
+                check_reminder_alarms(&new.alarms, new.due_date.is_some(), &new.title)?;
+                self.build_reminder(
+                    &new.title,
+                    new.notes.as_deref(),
+                    new.calendar_title.as_deref(),
+                    new.priority,
+                    &new.alarms,
+                    new.due_date,
+                    new.url.as_deref(),
+                )
+            })
+            .collect();
+
+        if self.dry_run {
+            return Ok(staged
+                .into_iter()
+                .map(|r| r.and_then(|reminder| self.finish_reminder(&reminder, false)))
+                .collect());
         }
 
-        // Set calendar
-        let calendar = if let Some(cal_title) = calendar_title {
-            self.find_calendar_by_title(cal_title)?
-        } else {
-            unsafe { self.store.defaultCalendarForNewReminders() }
-                .ok_or(RemindersError::NoDefaultCalendar)?
-        };
-        unsafe { reminder.setCalendar(Some(&calendar)) };
+        let staged: Vec<Result<Retained<EKReminder>>> = staged
+            .into_iter()
+            .map(|r| r.and_then(|reminder| self.save_reminder(&reminder, false).map(|_| reminder)))
+            .collect();
+        self.commit()?;
+
+        // Converted after the commit so the identifiers are final
+        Ok(staged
+            .into_iter()
+            .map(|r| r.map(|reminder| reminder_to_item(&reminder)))
+            .collect())
+    }
 
-        // Save
-        unsafe {
-            self.store
-                .saveReminder_commit_error(&reminder, true)
-                .map_err(|e| RemindersError::SaveFailed(format!("{:?}", e)))?;
-        }
+    /// Commits all staged changes to the event store
+    pub fn commit(&self) -> Result<()> {
+        unsafe { self.store.commit() }.map_err(|e| RemindersError::SaveFailed(format!("{:?}", e)))
+    }
 
-        Ok(reminder_to_item(&reminder))
+    /// Discards all uncommitted changes
+    ///
+    /// This drops everything staged since the last commit, including changes
+    /// to reminders that were fetched and then modified, and makes previously
+    /// fetched EventKit objects stale.
+    pub fn reset(&self) {
+        unsafe { self.store.reset() };
+        self.calendars.invalidate();
     }
 
     /// Updates an existing reminder
+    ///
+    /// `priority` must be 0-9, otherwise [`EventKitError::InvalidPriority`]
+    /// is returned and the reminder is unchanged.
+    ///
+    /// `due_date` is `Some(Some(date))` to set the due date, `Some(None)` to
+    /// clear it, or `None` to leave it alone. Clearing it on a reminder with
+    /// relative alarms returns [`EventKitError::NoDueDate`], since they'd
+    /// have nothing to fire relative to. `url` works the same way, and must
+    /// be absolute or [`EventKitError::InvalidUrl`] is returned.
+    #[allow(clippy::too_many_arguments)]
     pub fn update_reminder(
         &self,
-        identifier: &str,
+        identifier: &ReminderId,
         title: Option<&str>,
         notes: Option<&str>,
         completed: Option<bool>,
-        priority: Option<usize>,
+        priority: Option<Priority>,
+        due_date: Option<Option<DateTime<Local>>>,
+        url: Option<Option<&str>>,
     ) -> Result<ReminderItem> {
         self.ensure_authorized()?;
+        priority.map(check_priority).transpose()?;
+        let url = url.map(|u| u.map(parse_url).transpose()).transpose()?;
 
         let reminder = self.find_reminder_by_id(identifier)?;
+        if due_date == Some(None) {
+            check_reminder_alarms(&alarms_from_item(&reminder), false, &item_title(&reminder))?;
+        }
 
         if let Some(t) = title {
             let ns_title = NSString::from_str(t);
@@ -426,608 +1543,7385 @@ impl RemindersManager {
         }
 
         if let Some(p) = priority {
-            unsafe { reminder.setPriority(p) };
+            unsafe { reminder.setPriority(p.value().into()) };
         }
 
-        unsafe {
-            self.store
-                .saveReminder_commit_error(&reminder, true)
-                .map_err(|e| RemindersError::SaveFailed(format!("{:?}", e)))?;
+        if let Some(due) = due_date {
+            let components = due.map(|d| datetime_to_components(d, true));
+            unsafe { reminder.setDueDateComponents(components.as_deref()) };
         }
 
-        Ok(reminder_to_item(&reminder))
-    }
+        if let Some(u) = url {
+            unsafe { reminder.setURL(u.as_deref()) };
+        }
 
-    /// Marks a reminder as complete
-    pub fn complete_reminder(&self, identifier: &str) -> Result<ReminderItem> {
-        self.update_reminder(identifier, None, None, Some(true), None)
+        self.finish_reminder(&reminder, true)
     }
 
-    /// Marks a reminder as incomplete
-    pub fn uncomplete_reminder(&self, identifier: &str) -> Result<ReminderItem> {
-        self.update_reminder(identifier, None, None, Some(false), None)
+    /// Marks a reminder as complete
+    pub fn complete_reminder(&self, identifier: &ReminderId) -> Result<ReminderItem> {
+        self.update_reminder(identifier, None, None, Some(true), None, None, None)
     }
 
-    /// Deletes a reminder
-    pub fn delete_reminder(&self, identifier: &str) -> Result<()> {
+    /// Marks a reminder as complete as of the given time
+    ///
+    /// For recording work finished earlier: `completion_date` shows up as
+    /// [`ReminderItem::completion_date`] instead of the current time.
+    pub fn complete_reminder_at(
+        &self,
+        identifier: &ReminderId,
+        completed_at: DateTime<Local>,
+    ) -> Result<ReminderItem> {
         self.ensure_authorized()?;
 
         let reminder = self.find_reminder_by_id(identifier)?;
-
+        let date = datetime_to_nsdate(completed_at);
         unsafe {
-            self.store
-                .removeReminder_commit_error(&reminder, true)
-                .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))?;
+            reminder.setCompleted(true);
+            reminder.setCompletionDate(Some(&date));
         }
 
-        Ok(())
+        self.finish_reminder(&reminder, true)
     }
 
-    /// Gets a reminder by its identifier
-    pub fn get_reminder(&self, identifier: &str) -> Result<ReminderItem> {
+    /// Marks a reminder as incomplete
+    pub fn uncomplete_reminder(&self, identifier: &ReminderId) -> Result<ReminderItem> {
+        self.update_reminder(identifier, None, None, Some(false), None, None, None)
+    }
+
+    /// Pushes a reminder's due date forward (or back, for a negative duration)
+    ///
+    /// A date-only due date stays date-only when snoozed by whole days.
+    /// Relative alarms follow the due date on their own; absolute alarms are
+    /// moved by the same amount so they still go off before it. Returns
+    /// [`EventKitError::NoDueDate`] if the reminder has no due date, rather
+    /// than inventing one.
+    pub fn snooze_reminder(&self, identifier: &ReminderId, by: Duration) -> Result<ReminderItem> {
         self.ensure_authorized()?;
+
         let reminder = self.find_reminder_by_id(identifier)?;
-        Ok(reminder_to_item(&reminder))
+        let components = unsafe { reminder.dueDateComponents() }
+            .ok_or_else(|| EventKitError::NoDueDate(item_title(&reminder)))?;
+        let due = components_to_datetime(&components)
+            .ok_or_else(|| EventKitError::NoDueDate(item_title(&reminder)))?;
+
+        let whole_days = by.num_seconds() % Duration::days(1).num_seconds() == 0;
+        let with_time = components_have_time(&components) || !whole_days;
+        let new_components = datetime_to_components(due + by, with_time);
+        unsafe { reminder.setDueDateComponents(Some(&new_components)) };
+        shift_absolute_alarms(&reminder, by);
+
+        self.finish_reminder(&reminder, true)
     }
 
-    // Helper to find a calendar by title
-    fn find_calendar_by_title(&self, title: &str) -> Result<Retained<EKCalendar>> {
-        let calendars = unsafe { self.store.calendarsForEntityType(EKEntityType::Reminder) };
+    /// Replaces a reminder's due date, adding one if it had none
+    ///
+    /// As with [`snooze_reminder`](Self::snooze_reminder), absolute alarms
+    /// move by however far the due date moved. A reminder that had no due
+    /// date keeps its absolute alarms where they are.
+    pub fn reschedule_reminder(
+        &self,
+        identifier: &ReminderId,
+        new_due: DateTime<Local>,
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
-        for cal in calendars.iter() {
-            let cal_title = unsafe { cal.title() };
-            if cal_title.to_string() == title {
-                return Ok(cal.retain());
-            }
+        let reminder = self.find_reminder_by_id(identifier)?;
+        let old_due =
+            unsafe { reminder.dueDateComponents() }.and_then(|c| components_to_datetime(&c));
+        let components = datetime_to_components(new_due, true);
+        unsafe { reminder.setDueDateComponents(Some(&components)) };
+        if let Some(old_due) = old_due {
+            shift_absolute_alarms(&reminder, new_due - old_due);
         }
 
-        Err(RemindersError::CalendarNotFound(title.to_string()))
+        self.finish_reminder(&reminder, true)
     }
 
-    // Helper to find a reminder by identifier
-    fn find_reminder_by_id(&self, identifier: &str) -> Result<Retained<EKReminder>> {
-        let ns_id = NSString::from_str(identifier);
-        let item = unsafe { self.store.calendarItemWithIdentifier(&ns_id) };
+    /// Skips the next occurrence of a recurring reminder
+    ///
+    /// Unlike events, a recurring reminder is a single item with no
+    /// per-occurrence copies and no `EKSpan`: only the current occurrence
+    /// exists, and deleting the reminder removes the whole series. Skipping
+    /// therefore moves the due date on to the following occurrence, and
+    /// lowers an occurrence-count end by one so the series still finishes
+    /// where it would have. Completing the reminder instead records the
+    /// occurrence as done.
+    ///
+    /// Only reminders with a single rule that doesn't pick particular days
+    /// (see [`RecurrenceRule::days_of_week`] and
+    /// [`RecurrenceRule::has_day_constraints`]) can be skipped; anything else,
+    /// or skipping the last occurrence, returns
+    /// [`EventKitError::UnsupportedRecurrence`]. A reminder without a due date
+    /// returns [`EventKitError::NoDueDate`].
+    pub fn skip_reminder_occurrence(&self, identifier: &ReminderId) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
-        match item {
-            Some(item) => {
-                // Try to downcast to EKReminder
-                if let Some(reminder) = item.downcast_ref::<EKReminder>() {
-                    Ok(reminder.retain())
-                } else {
-                    Err(EventKitError::ItemNotFound(identifier.to_string()))
-                }
+        let reminder = self.find_reminder_by_id(identifier)?;
+        let components = unsafe { reminder.dueDateComponents() }
+            .ok_or_else(|| EventKitError::NoDueDate(item_title(&reminder)))?;
+        let due = components_to_datetime(&components)
+            .ok_or_else(|| EventKitError::NoDueDate(item_title(&reminder)))?;
+
+        let unsupported = |why: &str| EventKitError::UnsupportedRecurrence(why.to_string());
+        let ek_rules = unsafe { reminder.recurrenceRules() }.unwrap_or_default();
+        let ek_rule = match ek_rules.count() {
+            0 => return Err(unsupported("the reminder doesn't repeat")),
+            1 => ek_rules.objectAtIndex(0),
+            _ => {
+                return Err(unsupported(
+                    "the reminder has more than one recurrence rule",
+                ));
             }
-            None => Err(EventKitError::ItemNotFound(identifier.to_string())),
+        };
+        let rule = recurrence_from_ek(&ek_rule)
+            .filter(|r| !r.has_day_constraints() && r.days_of_week.is_empty())
+            .ok_or_else(|| unsupported("the reminder repeats on specific days"))?;
+        let next = next_occurrence(due, &rule)
+            .ok_or_else(|| unsupported("it's the last occurrence; delete the reminder instead"))?;
+
+        let new_components = datetime_to_components(next, components_have_time(&components));
+        unsafe { reminder.setDueDateComponents(Some(&new_components)) };
+
+        if let Some(RecurrenceEnd::Count(n)) = rule.end {
+            let rule = RecurrenceRule {
+                end: Some(RecurrenceEnd::Count(n - 1)),
+                ..rule
+            };
+            let rules = NSArray::from_retained_slice(&[recurrence_to_ek(&rule)]);
+            unsafe { reminder.setRecurrenceRules(Some(&rules)) };
         }
-    }
-}
 
-impl Default for RemindersManager {
-    fn default() -> Self {
-        Self::new()
+        self.finish_reminder(&reminder, true)
     }
-}
 
-/// Authorization status for reminders access
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub enum AuthorizationStatus {
-    /// User has not yet made a choice
-    NotDetermined,
-    /// Access restricted by system policy
-    Restricted,
-    /// User explicitly denied access
-    Denied,
-    /// Full access granted
-    FullAccess,
-    /// Write-only access granted
-    WriteOnly,
-}
+    /// Sets or clears the URL attached to a reminder
+    ///
+    /// The URL must be absolute (include a scheme such as `https:`), otherwise
+    /// [`EventKitError::InvalidUrl`] is returned and the reminder is unchanged.
+    pub fn set_reminder_url(
+        &self,
+        identifier: &ReminderId,
+        url: Option<&str>,
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
-impl From<EKAuthorizationStatus> for AuthorizationStatus {
-    fn from(status: EKAuthorizationStatus) -> Self {
-        if status == EKAuthorizationStatus::NotDetermined {
-            AuthorizationStatus::NotDetermined
-        } else if status == EKAuthorizationStatus::Restricted {
-            AuthorizationStatus::Restricted
-        } else if status == EKAuthorizationStatus::Denied {
-            AuthorizationStatus::Denied
-        } else if status == EKAuthorizationStatus::FullAccess {
-            AuthorizationStatus::FullAccess
-        } else if status == EKAuthorizationStatus::WriteOnly {
-            AuthorizationStatus::WriteOnly
-        } else {
-            AuthorizationStatus::NotDetermined
-        }
+        let ns_url = url.map(parse_url).transpose()?;
+        let reminder = self.find_reminder_by_id(identifier)?;
+        unsafe { reminder.setURL(ns_url.as_deref()) };
+
+        self.finish_reminder(&reminder, true)
     }
-}
 
-impl std::fmt::Display for AuthorizationStatus {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            AuthorizationStatus::NotDetermined => write!(f, "Not Determined"),
-            AuthorizationStatus::Restricted => write!(f, "Restricted"),
-            AuthorizationStatus::Denied => write!(f, "Denied"),
-            AuthorizationStatus::FullAccess => write!(f, "Full Access"),
-            AuthorizationStatus::WriteOnly => write!(f, "Write Only"),
-        }
-    }
-}
-
-// Helper function to convert EKReminder to ReminderItem
-fn reminder_to_item(reminder: &EKReminder) -> ReminderItem {
-    let identifier = unsafe { reminder.calendarItemIdentifier() }.to_string();
-    let title = unsafe { reminder.title() }.to_string();
-    let notes = unsafe { reminder.notes() }.map(|n| n.to_string());
-    let completed = unsafe { reminder.isCompleted() };
-    let priority = unsafe { reminder.priority() };
-    let calendar_title = unsafe { reminder.calendar() }.map(|c| unsafe { c.title() }.to_string());
+    /// Replaces all of a reminder's alarms
+    ///
+    /// Pass an empty slice to remove them. Relative alarms fire at an offset
+    /// from the due date, so if any are given and the reminder has no due
+    /// date, [`EventKitError::NoDueDate`] is returned and the reminder is
+    /// unchanged.
+    pub fn set_reminder_alarms(
+        &self,
+        identifier: &ReminderId,
+        alarms: &[Alarm],
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
-    ReminderItem {
-        identifier,
-        title,
-        notes,
-        completed,
-        priority,
-        calendar_title,
-    }
-}
+        let reminder = self.find_reminder_by_id(identifier)?;
+        let has_due_date = unsafe { reminder.dueDateComponents() }.is_some();
+        check_reminder_alarms(alarms, has_due_date, &item_title(&reminder))?;
 
-// Helper function to convert EKCalendar to CalendarInfo
-fn calendar_to_info(calendar: &EKCalendar) -> CalendarInfo {
-    let identifier = unsafe { calendar.calendarIdentifier() }.to_string();
-    let title = unsafe { calendar.title() }.to_string();
-    let source = unsafe { calendar.source() }.map(|s| unsafe { s.title() }.to_string());
-    let allows_modifications = unsafe { calendar.allowsContentModifications() };
+        let alarms: Vec<Retained<EKAlarm>> = alarms.iter().map(alarm_to_ek).collect();
+        unsafe { reminder.setAlarms(Some(&NSArray::from_retained_slice(&alarms))) };
 
-    CalendarInfo {
-        identifier,
-        title,
-        source,
-        allows_modifications,
+        self.finish_reminder(&reminder, true)
     }
-}
 
-// ============================================================================
-// Calendar Events Support
-// ============================================================================
+    /// Adds one alarm to a reminder, keeping the ones it already has
+    ///
+    /// The usual way to make a location-based reminder: pass
+    /// [`Alarm::on_arrival`] or [`Alarm::on_departure`] to be reminded on
+    /// reaching or leaving a place. As with
+    /// [`set_reminder_alarms`](Self::set_reminder_alarms), a relative alarm
+    /// on a reminder with no due date returns [`EventKitError::NoDueDate`],
+    /// and a location with out-of-range coordinates returns
+    /// [`EventKitError::InvalidLocation`].
+    pub fn add_reminder_alarm(
+        &self,
+        identifier: &ReminderId,
+        alarm: Alarm,
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
-/// Represents a calendar event with its properties
-#[derive(Debug, Clone)]
-pub struct EventItem {
-    /// Unique identifier for the event
-    pub identifier: String,
-    /// Title of the event
-    pub title: String,
-    /// Optional notes/description
-    pub notes: Option<String>,
-    /// Optional location
-    pub location: Option<String>,
-    /// Start date/time
-    pub start_date: DateTime<Local>,
-    /// End date/time
-    pub end_date: DateTime<Local>,
-    /// Whether this is an all-day event
-    pub all_day: bool,
-    /// Calendar the event belongs to
-    pub calendar_title: Option<String>,
-}
+        let reminder = self.find_reminder_by_id(identifier)?;
+        let has_due_date = unsafe { reminder.dueDateComponents() }.is_some();
+        check_reminder_alarms(
+            std::slice::from_ref(&alarm),
+            has_due_date,
+            &item_title(&reminder),
+        )?;
 
-/// The events manager providing access to Calendar events via EventKit
-pub struct EventsManager {
-    store: Retained<EKEventStore>,
-}
+        unsafe { reminder.addAlarm(&alarm_to_ek(&alarm)) };
 
-impl EventsManager {
-    /// Creates a new EventsManager instance
-    pub fn new() -> Self {
-        let store = unsafe { EKEventStore::new() };
-        Self { store }
+        self.finish_reminder(&reminder, true)
     }
 
-    /// Gets the current authorization status for calendar events
-    pub fn authorization_status() -> AuthorizationStatus {
-        let status = unsafe { EKEventStore::authorizationStatusForEntityType(EKEntityType::Event) };
-        status.into()
+    /// Moves a reminder to another list, found by title
+    ///
+    /// Titles aren't unique; the first matching list is used, so prefer
+    /// [`move_reminder_to_calendar`](Self::move_reminder_to_calendar) when the
+    /// identifier is known. The target must be able to hold reminders,
+    /// otherwise [`EventKitError::CalendarWrongType`] is returned. The
+    /// reminder keeps its identifier.
+    pub fn move_reminder(&self, identifier: &ReminderId, list_title: &str) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
+
+        let calendar = self.find_calendar_by_title(list_title)?;
+        self.relocate_reminder(identifier, &calendar)
     }
 
-    /// Requests full access to calendar events (blocking)
+    /// Moves a reminder to the list with this identifier
     ///
-    /// Returns Ok(true) if access was granted, Ok(false) if denied
-    pub fn request_access(&self) -> Result<bool> {
-        let result = Arc::new((Mutex::new(None::<(bool, Option<String>)>), Condvar::new()));
-        let result_clone = Arc::clone(&result);
+    /// Returns [`EventKitError::CalendarNotFound`] if no list has the
+    /// identifier. Otherwise behaves like [`move_reminder`](Self::move_reminder).
+    pub fn move_reminder_to_calendar(
+        &self,
+        identifier: &ReminderId,
+        calendar_id: &CalendarId,
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
-        let completion = RcBlock::new(move |granted: Bool, error: *mut NSError| {
-            let error_msg = if !error.is_null() {
-                let error_ref = unsafe { &*error };
-                Some(format!("{:?}", error_ref))
-            } else {
-                None
-            };
+        let calendar = self
+            .calendars
+            .by_id(calendar_id)
+            .ok_or_else(|| EventKitError::CalendarNotFound(calendar_id.to_string()))?;
+        self.relocate_reminder(identifier, &calendar)
+    }
 
-            let (lock, cvar) = &*result_clone;
-            let mut res = lock.lock().unwrap();
-            *res = Some((granted.as_bool(), error_msg));
-            cvar.notify_one();
-        });
+    /// Copies a reminder into a new, incomplete reminder
+    ///
+    /// The copy keeps the title, notes, priority, alarms, URL and due date. It
+    /// goes into `calendar_title` if given, otherwise the source's list, and
+    /// gets its own identifier.
+    pub fn duplicate_reminder(
+        &self,
+        identifier: &ReminderId,
+        calendar_title: Option<&str>,
+    ) -> Result<ReminderItem> {
+        self.ensure_authorized()?;
 
+        let source = self.find_reminder_by_id(identifier)?;
+        let item = reminder_to_item(&source);
+
+        let reminder = self.build_reminder(
+            &item.title,
+            item.notes.as_deref(),
+            calendar_title.or(item.calendar_title.as_deref()),
+            Some(item.priority),
+            &item.alarms,
+            None,
+            None,
+        )?;
         unsafe {
-            let block_ptr = &*completion as *const _ as *mut _;
-            self.store
-                .requestFullAccessToEventsWithCompletion(block_ptr);
+            reminder.setURL(source.URL().as_deref());
+            reminder.setDueDateComponents(source.dueDateComponents().as_deref());
         }
 
-        let (lock, cvar) = &*result;
-        let mut res = lock.lock().unwrap();
-        while res.is_none() {
-            res = cvar.wait(res).unwrap();
+        // List titles aren't unique, so keep the exact source list
+        if calendar_title.is_none()
+            && let Some(calendar) = unsafe { source.calendar() }
+        {
+            unsafe { reminder.setCalendar(Some(&calendar)) };
         }
 
-        match res.take() {
-            Some((granted, None)) => Ok(granted),
-            Some((_, Some(error))) => Err(EventKitError::AuthorizationRequestFailed(error)),
-            None => Err(EventKitError::AuthorizationRequestFailed(
-                "Unknown error".to_string(),
-            )),
-        }
+        self.finish_reminder(&reminder, true)
     }
 
-    /// Ensures we have authorization, requesting if needed
-    pub fn ensure_authorized(&self) -> Result<()> {
-        match Self::authorization_status() {
-            AuthorizationStatus::FullAccess => Ok(()),
-            AuthorizationStatus::NotDetermined => {
-                if self.request_access()? {
-                    Ok(())
-                } else {
-                    Err(EventKitError::AuthorizationDenied)
-                }
-            }
-            AuthorizationStatus::Denied => Err(EventKitError::AuthorizationDenied),
-            AuthorizationStatus::Restricted => Err(EventKitError::AuthorizationRestricted),
-            AuthorizationStatus::WriteOnly => Ok(()),
+    /// Deletes a reminder
+    pub fn delete_reminder(&self, identifier: &ReminderId) -> Result<()> {
+        self.ensure_authorized()?;
+
+        let reminder = self.find_reminder_by_id(identifier)?;
+        self.remove_reminder(&reminder, true)
+    }
+
+    /// Deletes several reminders with a single commit
+    ///
+    /// Each identifier is attempted on its own: one that can't be found or
+    /// removed is reported in the returned list without stopping the rest.
+    /// An error is only returned for the call as a whole when access is
+    /// denied or the final commit fails; the removals then stay staged and
+    /// can be retried with [`commit`](Self::commit) or dropped with
+    /// [`reset`](Self::reset).
+    pub fn delete_reminders(
+        &self,
+        identifiers: &[ReminderId],
+    ) -> Result<Vec<(ReminderId, Result<()>)>> {
+        self.ensure_authorized()?;
+
+        let results = identifiers
+            .iter()
+            .map(|id| {
+                let outcome = self
+                    .find_reminder_by_id(id)
+                    .and_then(|reminder| self.remove_reminder(&reminder, false));
+                (id.clone(), outcome)
+            })
+            .collect();
+
+        if !self.dry_run {
+            unsafe { self.store.commit() }
+                .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))?;
         }
+
+        Ok(results)
     }
 
-    /// Lists all event calendars
-    pub fn list_calendars(&self) -> Result<Vec<CalendarInfo>> {
+    /// Marks every reminder matching `filter` as complete, with a single commit
+    ///
+    /// Returns how many reminders were completed; ones that already were
+    /// aren't counted. In dry-run mode nothing changes and the count is of
+    /// the reminders that would be completed. If a save or the final commit
+    /// fails the error is returned and the changes so far stay staged, to be
+    /// retried with [`commit`](Self::commit) or dropped with
+    /// [`reset`](Self::reset).
+    pub fn complete_matching(&self, filter: &ReminderFilter) -> Result<usize> {
         self.ensure_authorized()?;
 
-        let calendars = unsafe { self.store.calendarsForEntityType(EKEntityType::Event) };
+        // Only incomplete reminders can be completed
+        if filter.completed == Some(true) {
+            return Ok(0);
+        }
+        let filter = ReminderFilter {
+            completed: Some(false),
+            ..filter.clone()
+        };
+        let matches = self.fetch_filtered(&filter, None)?;
+        if self.dry_run {
+            return Ok(matches.len());
+        }
 
-        let mut result = Vec::new();
-        for calendar in calendars.iter() {
-            result.push(calendar_to_info(&calendar));
+        for item in &matches {
+            let reminder = self.find_reminder_by_id(&item.identifier)?;
+            unsafe { reminder.setCompleted(true) };
+            self.save_reminder(&reminder, false)?;
         }
+        self.commit()?;
 
-        Ok(result)
+        Ok(matches.len())
     }
 
-    /// Gets the default calendar for new events
-    pub fn default_calendar(&self) -> Result<CalendarInfo> {
+    /// Deletes every reminder matching `filter`, with a single commit
+    ///
+    /// Returns how many reminders were deleted. An empty filter matches
+    /// everything, so check it before passing user input through. Dry-run
+    /// mode and failures behave as in
+    /// [`complete_matching`](Self::complete_matching).
+    pub fn delete_matching(&self, filter: &ReminderFilter) -> Result<usize> {
         self.ensure_authorized()?;
 
-        let calendar = unsafe { self.store.defaultCalendarForNewEvents() };
+        let matches = self.fetch_filtered(filter, None)?;
+        if self.dry_run {
+            return Ok(matches.len());
+        }
 
-        match calendar {
-            Some(cal) => Ok(calendar_to_info(&cal)),
-            None => Err(EventKitError::NoDefaultCalendar),
+        for item in &matches {
+            let reminder = self.find_reminder_by_id(&item.identifier)?;
+            self.remove_reminder(&reminder, false)?;
         }
+        unsafe { self.store.commit() }
+            .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))?;
+
+        Ok(matches.len())
     }
 
-    /// Fetches events for today
-    pub fn fetch_today_events(&self) -> Result<Vec<EventItem>> {
-        let now = Local::now();
-        let start = now.date_naive().and_hms_opt(0, 0, 0).unwrap();
-        let end = now.date_naive().and_hms_opt(23, 59, 59).unwrap();
+    /// Finds reminders whose title or notes contain `query`, ignoring case
+    /// (blocking)
+    ///
+    /// `scope` narrows the search by list and completion state; its `text`
+    /// is replaced by `query`. Results are sorted like
+    /// [`fetch_reminders`](Self::fetch_reminders).
+    pub fn search_reminders(
+        &self,
+        query: &str,
+        scope: &ReminderFilter,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
 
-        self.fetch_events(
-            Local.from_local_datetime(&start).unwrap(),
-            Local.from_local_datetime(&end).unwrap(),
-            None,
-        )
+        let filter = ReminderFilter {
+            text: Some(query.to_string()),
+            ..scope.clone()
+        };
+        let mut reminders = self.fetch_filtered(&filter, None)?;
+        sort_reminders_default(&mut reminders);
+        Ok(reminders)
     }
 
-    /// Fetches events for the next N days
-    pub fn fetch_upcoming_events(&self, days: i64) -> Result<Vec<EventItem>> {
-        let now = Local::now();
-        let end = now + Duration::days(days);
-        self.fetch_events(now, end, None)
+    /// Finds reminders whose title or notes match the regular expression
+    /// `pattern`, ignoring case (blocking)
+    ///
+    /// Like [`search_reminders`](Self::search_reminders), but returns
+    /// [`EventKitError::InvalidPattern`] if `pattern` doesn't compile.
+    #[cfg(feature = "regex")]
+    pub fn search_reminders_regex(
+        &self,
+        pattern: &str,
+        scope: &ReminderFilter,
+    ) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+
+        let regex = regex::RegexBuilder::new(pattern)
+            .case_insensitive(true)
+            .build()
+            .map_err(|e| EventKitError::InvalidPattern(e.to_string()))?;
+        let mut reminders = self.fetch_filtered(scope, None)?;
+        reminders.retain(|r| {
+            regex.is_match(&r.title) || r.notes.as_deref().is_some_and(|n| regex.is_match(n))
+        });
+        sort_reminders_default(&mut reminders);
+        Ok(reminders)
     }
 
-    /// Fetches events in a date range
-    pub fn fetch_events(
-        &self,
-        start: DateTime<Local>,
-        end: DateTime<Local>,
-        calendar_titles: Option<&[&str]>,
-    ) -> Result<Vec<EventItem>> {
+    /// Gets a reminder by its identifier
+    pub fn get_reminder(&self, identifier: &ReminderId) -> Result<ReminderItem> {
         self.ensure_authorized()?;
+        let reminder = self.find_reminder_by_id(identifier)?;
+        Ok(reminder_to_item(&reminder))
+    }
 
-        if start >= end {
-            return Err(EventKitError::InvalidDateRange);
+    /// Gets all reminders with the given external identifier
+    ///
+    /// External identifiers come from the calendar server and can match more
+    /// than one item, so every match is returned (possibly none).
+    pub fn get_reminder_by_external_id(&self, external_id: &str) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        let ns_id = NSString::from_str(external_id);
+        let items = unsafe { self.store.calendarItemsWithExternalIdentifier(&ns_id) };
+
+        Ok(items
+            .iter()
+            .filter_map(|item| item.downcast_ref::<EKReminder>().map(reminder_to_item))
+            .collect())
+    }
+
+    // Helper to fetch and convert every reminder matching a predicate (blocking)
+    fn fetch_matching(&self, predicate: &NSPredicate) -> Result<Vec<ReminderItem>> {
+        self.fetch_matching_where(predicate, |_| true)
+    }
+
+    // Helper like `fetch_matching` that only converts reminders passing `keep`
+    fn fetch_matching_where(
+        &self,
+        predicate: &NSPredicate,
+        keep: impl Fn(&EKReminder) -> bool + 'static,
+    ) -> Result<Vec<ReminderItem>> {
+        let result = Arc::new((Mutex::new(None::<Vec<ReminderItem>>), Condvar::new()));
+        let result_clone = Arc::clone(&result);
+
+        let completion = RcBlock::new(move |reminders: *mut NSArray<EKReminder>| {
+            let mut items: Vec<ReminderItem> = if reminders.is_null() {
+                Vec::new()
+            } else {
+                let reminders = unsafe { Retained::retain(reminders).unwrap() };
+                reminders
+                    .iter()
+                    .filter(|r| keep(r))
+                    .map(|r| reminder_to_item(&r))
+                    .collect()
+            };
+            dedup_by_key(&mut items, |r| r.identifier.clone());
+            let (lock, cvar) = &*result_clone;
+            let mut guard = lock.lock().unwrap();
+            *guard = Some(items);
+            cvar.notify_one();
+        });
+
+        unsafe {
+            self.store
+                .fetchRemindersMatchingPredicate_completion(predicate, &completion);
         }
 
-        let calendars: Option<Retained<NSArray<EKCalendar>>> = match calendar_titles {
-            Some(titles) => {
-                let all_calendars =
-                    unsafe { self.store.calendarsForEntityType(EKEntityType::Event) };
-                let mut matching: Vec<Retained<EKCalendar>> = Vec::new();
-
-                for cal in all_calendars.iter() {
-                    let title = unsafe { cal.title() };
-                    let title_str = title.to_string();
-                    if titles.iter().any(|t| *t == title_str) {
-                        matching.push(cal.retain());
-                    }
-                }
+        wait_for_completion(&result, self.timeout)
+    }
 
-                if matching.is_empty() {
-                    return Err(EventKitError::CalendarNotFound(titles.join(", ")));
+    // Helper to run a ReminderQuery
+    fn fetch_query(&self, query: &ReminderQuery) -> Result<Vec<ReminderItem>> {
+        self.ensure_authorized()?;
+        if let (Some(start), Some(end)) = (query.due_after, query.filter.due_before)
+            && start >= end
+        {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let mut reminders = self.fetch_filtered(&query.filter, query.due_after)?;
+        if query.due_after.is_some() {
+            reminders.retain(|r| query.matches(r));
+        }
+        match (query.sort, query.limit) {
+            (Some(sort), Some(limit)) => sort.sort_reminders_limited(&mut reminders, limit),
+            (Some(sort), None) => sort.sort_reminders(&mut reminders),
+            (None, limit) => {
+                sort_reminders_default(&mut reminders);
+                if let Some(limit) = limit {
+                    reminders.truncate(limit);
                 }
+            }
+        }
+        Ok(reminders)
+    }
 
-                Some(NSArray::from_retained_slice(&matching))
+    // Helper to fetch the reminders matching a filter and due no earlier
+    // than `due_after`, narrowing the predicate as far as EventKit allows
+    fn fetch_filtered(
+        &self,
+        filter: &ReminderFilter,
+        due_after: Option<DateTime<Local>>,
+    ) -> Result<Vec<ReminderItem>> {
+        self.refresh_if_enabled();
+
+        let titles: Option<Vec<&str>> = filter
+            .calendar_titles
+            .as_ref()
+            .map(|titles| titles.iter().map(String::as_str).collect());
+        let calendars = self.calendars_matching(titles.as_deref())?;
+        let due_before = filter.due_before.map(datetime_to_nsdate);
+        let due_after = due_after.map(datetime_to_nsdate);
+        let predicate = unsafe {
+            match filter.completed {
+                Some(false) => self
+                    .store
+                    .predicateForIncompleteRemindersWithDueDateStarting_ending_calendars(
+                        due_after.as_deref(),
+                        due_before.as_deref(),
+                        calendars.as_deref(),
+                    ),
+                Some(true) => self
+                    .store
+                    .predicateForCompletedRemindersWithCompletionDateStarting_ending_calendars(
+                        None,
+                        None,
+                        calendars.as_deref(),
+                    ),
+                None => self
+                    .store
+                    .predicateForRemindersInCalendars(calendars.as_deref()),
             }
-            None => None,
         };
 
-        let start_date = datetime_to_nsdate(start);
-        let end_date = datetime_to_nsdate(end);
+        let mut reminders = self.fetch_matching(&predicate)?;
+        reminders.retain(|r| filter.matches(r));
+        Ok(reminders)
+    }
 
-        let predicate = unsafe {
+    // Helper to build a predicate for all incomplete reminders
+    fn incomplete_reminders_predicate(&self) -> Retained<NSPredicate> {
+        unsafe {
             self.store
-                .predicateForEventsWithStartDate_endDate_calendars(
-                    &start_date,
-                    &end_date,
-                    calendars.as_deref(),
+                .predicateForIncompleteRemindersWithDueDateStarting_ending_calendars(
+                    None, None, None,
                 )
-        };
-
-        let events = unsafe { self.store.eventsMatchingPredicate(&predicate) };
-
-        let mut items = Vec::new();
-        for event in events.iter() {
-            items.push(event_to_item(&event));
         }
+    }
+
+    // Helper to re-save a reminder in another calendar
+    fn relocate_reminder(
+        &self,
+        identifier: &ReminderId,
+        calendar: &EKCalendar,
+    ) -> Result<ReminderItem> {
+        ensure_calendar_allows(calendar, EntityType::Reminder)?;
 
-        // Sort by start date
-        items.sort_by(|a, b| a.start_date.cmp(&b.start_date));
+        let reminder = self.find_reminder_by_id(identifier)?;
+        unsafe { reminder.setCalendar(Some(calendar)) };
 
-        Ok(items)
+        self.finish_reminder(&reminder, true)
     }
 
-    /// Creates a new event
+    // Helper to build (but not save) a new reminder
     #[allow(clippy::too_many_arguments)]
-    pub fn create_event(
+    fn build_reminder(
         &self,
         title: &str,
-        start: DateTime<Local>,
-        end: DateTime<Local>,
         notes: Option<&str>,
-        location: Option<&str>,
         calendar_title: Option<&str>,
-        all_day: bool,
-    ) -> Result<EventItem> {
-        self.ensure_authorized()?;
-
-        let event = unsafe { EKEvent::eventWithEventStore(&self.store) };
+        priority: Option<Priority>,
+        alarms: &[Alarm],
+        due_date: Option<DateTime<Local>>,
+        url: Option<&str>,
+    ) -> Result<Retained<EKReminder>> {
+        let url = url.map(parse_url).transpose()?;
+        let reminder = unsafe { EKReminder::reminderWithEventStore(&self.store) };
 
         // Set title
         let ns_title = NSString::from_str(title);
-        unsafe { event.setTitle(Some(&ns_title)) };
-
-        // Set dates
-        let start_date = datetime_to_nsdate(start);
-        let end_date = datetime_to_nsdate(end);
-        unsafe {
-            event.setStartDate(Some(&start_date));
-            event.setEndDate(Some(&end_date));
-            event.setAllDay(all_day);
-        }
+        unsafe { reminder.setTitle(Some(&ns_title)) };
 
         // Set notes if provided
         if let Some(notes_text) = notes {
             let ns_notes = NSString::from_str(notes_text);
-            unsafe { event.setNotes(Some(&ns_notes)) };
+            unsafe { reminder.setNotes(Some(&ns_notes)) };
         }
 
-        // Set location if provided
-        if let Some(loc) = location {
-            let ns_location = NSString::from_str(loc);
-            unsafe { event.setLocation(Some(&ns_location)) };
+        // Set priority if provided
+        if let Some(p) = priority {
+            check_priority(p)?;
+            unsafe { reminder.setPriority(p.value().into()) };
+        }
+
+        // Set due date if provided
+        if let Some(due) = due_date {
+            let components = datetime_to_components(due, true);
+            unsafe { reminder.setDueDateComponents(Some(&components)) };
+        }
+
+        // Link the URL if provided
+        if let Some(u) = url {
+            unsafe { reminder.setURL(Some(&u)) };
+        }
+
+        // Attach alarms
+        for alarm in alarms {
+            unsafe { reminder.addAlarm(&alarm_to_ek(alarm)) };
         }
 
         // Set calendar
         let calendar = if let Some(cal_title) = calendar_title {
             self.find_calendar_by_title(cal_title)?
         } else {
-            unsafe { self.store.defaultCalendarForNewEvents() }
-                .ok_or(EventKitError::NoDefaultCalendar)?
+            self.calendars
+                .default()
+                .ok_or(RemindersError::NoDefaultCalendar)?
         };
-        unsafe { event.setCalendar(Some(&calendar)) };
-
-        // Save
-        unsafe {
-            self.store
-                .saveEvent_span_error(&event, EKSpan::ThisEvent)
-                .map_err(|e| EventKitError::SaveFailed(format!("{:?}", e)))?;
-        }
+        ensure_calendar_allows(&calendar, EntityType::Reminder)?;
+        unsafe { reminder.setCalendar(Some(&calendar)) };
 
-        Ok(event_to_item(&event))
+        Ok(reminder)
     }
 
-    /// Updates an existing event
-    pub fn update_event(
-        &self,
-        identifier: &str,
-        title: Option<&str>,
-        notes: Option<&str>,
-        location: Option<&str>,
-        start: Option<DateTime<Local>>,
-        end: Option<DateTime<Local>>,
-    ) -> Result<EventItem> {
-        self.ensure_authorized()?;
+    // Helper to save a reminder and convert it; in dry-run mode the reminder is
+    // converted and its unsaved changes are rolled back instead
+    fn finish_reminder(&self, reminder: &EKReminder, commit: bool) -> Result<ReminderItem> {
+        if self.dry_run {
+            let mut item = reminder_to_item(reminder);
+            if unsafe { reminder.isNew() } {
+                item.identifier = Default::default();
+                item.external_identifier = None;
+            }
+            unsafe { reminder.rollback() };
+            return Ok(item);
+        }
 
-        let event = self.find_event_by_id(identifier)?;
+        self.save_reminder(reminder, commit)?;
+        Ok(reminder_to_item(reminder))
+    }
 
-        if let Some(t) = title {
-            let ns_title = NSString::from_str(t);
-            unsafe { event.setTitle(Some(&ns_title)) };
+    // Helper to remove a reminder, or do nothing in dry-run mode
+    fn remove_reminder(&self, reminder: &EKReminder, commit: bool) -> Result<()> {
+        if self.dry_run {
+            return Ok(());
         }
+        unsafe { self.store.removeReminder_commit_error(reminder, commit) }
+            .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))
+    }
 
-        if let Some(n) = notes {
-            let ns_notes = NSString::from_str(n);
-            unsafe { event.setNotes(Some(&ns_notes)) };
-        }
+    // Helper to save a reminder, retrying transient failures per the retry policy
+    fn save_reminder(&self, reminder: &EKReminder, commit: bool) -> Result<()> {
+        with_retry(&self.retry, is_transient_error, || unsafe {
+            self.store.saveReminder_commit_error(reminder, commit)
+        })
+        .map_err(|e| RemindersError::SaveFailed(format!("{:?}", e)))
+    }
 
-        if let Some(l) = location {
-            let ns_location = NSString::from_str(l);
-            unsafe { event.setLocation(Some(&ns_location)) };
+    // Helper to refresh sources before a fetch when auto-refresh is on
+    fn refresh_if_enabled(&self) {
+        if self.auto_refresh {
+            self.refresh();
         }
+    }
 
-        if let Some(s) = start {
-            let start_date = datetime_to_nsdate(s);
-            unsafe { event.setStartDate(Some(&start_date)) };
-        }
+    // Helper to find a calendar by title
+    fn find_calendar_by_title(&self, title: &str) -> Result<Retained<EKCalendar>> {
+        self.calendars.resolve_title(title, self.fuzzy_titles)
+    }
 
-        if let Some(e) = end {
-            let end_date = datetime_to_nsdate(e);
-            unsafe { event.setEndDate(Some(&end_date)) };
-        }
+    // Helper to build a predicate for reminders in the given calendars
+    fn reminders_predicate(
+        &self,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Retained<NSPredicate>> {
+        let calendars = self.calendars_matching(calendar_titles)?;
 
-        unsafe {
+        Ok(unsafe {
             self.store
-                .saveEvent_span_error(&event, EKSpan::ThisEvent)
-                .map_err(|e| EventKitError::SaveFailed(format!("{:?}", e)))?;
-        }
-
-        Ok(event_to_item(&event))
+                .predicateForRemindersInCalendars(calendars.as_deref())
+        })
     }
 
-    /// Deletes an event
-    pub fn delete_event(&self, identifier: &str) -> Result<()> {
-        self.ensure_authorized()?;
-
-        let event = self.find_event_by_id(identifier)?;
+    // Helper to resolve list titles to calendars; `None` means every list
+    fn calendars_matching(
+        &self,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Option<Retained<NSArray<EKCalendar>>>> {
+        match calendar_titles {
+            Some(titles) => {
+                let matching = self.calendars.matching_titles(titles);
+                if matching.is_empty() {
+                    return Err(RemindersError::CalendarNotFound(titles.join(", ")));
+                }
 
-        unsafe {
-            self.store
-                .removeEvent_span_error(&event, EKSpan::ThisEvent)
-                .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))?;
+                Ok(Some(NSArray::from_retained_slice(&matching)))
+            }
+            None => Ok(None),
         }
+    }
 
-        Ok(())
+    // Helper to find a reminder by identifier
+    fn find_reminder_by_id(&self, identifier: &str) -> Result<Retained<EKReminder>> {
+        let ns_id = NSString::from_str(identifier);
+        let item = unsafe { self.store.calendarItemWithIdentifier(&ns_id) };
+
+        match item {
+            Some(item) => {
+                // Try to downcast to EKReminder
+                if let Some(reminder) = item.downcast_ref::<EKReminder>() {
+                    Ok(reminder.retain())
+                } else {
+                    Err(EventKitError::ItemNotFound(identifier.to_string()))
+                }
+            }
+            None => Err(EventKitError::ItemNotFound(identifier.to_string())),
+        }
     }
+}
 
-    /// Gets an event by its identifier
-    pub fn get_event(&self, identifier: &str) -> Result<EventItem> {
-        self.ensure_authorized()?;
-        let event = self.find_event_by_id(identifier)?;
-        Ok(event_to_item(&event))
+impl Default for RemindersManager {
+    fn default() -> Self {
+        Self::new()
     }
+}
 
-    // Helper to find a calendar by title
-    fn find_calendar_by_title(&self, title: &str) -> Result<Retained<EKCalendar>> {
-        let calendars = unsafe { self.store.calendarsForEntityType(EKEntityType::Event) };
+/// Authorization status for reminders access
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthorizationStatus {
+    /// User has not yet made a choice
+    NotDetermined,
+    /// Access restricted by system policy
+    Restricted,
+    /// User explicitly denied access
+    Denied,
+    /// Full access granted
+    FullAccess,
+    /// Write-only access granted
+    WriteOnly,
+}
 
-        for cal in calendars.iter() {
-            let cal_title = unsafe { cal.title() };
-            if cal_title.to_string() == title {
-                return Ok(cal.retain());
-            }
+impl From<EKAuthorizationStatus> for AuthorizationStatus {
+    fn from(status: EKAuthorizationStatus) -> Self {
+        if status == EKAuthorizationStatus::NotDetermined {
+            AuthorizationStatus::NotDetermined
+        } else if status == EKAuthorizationStatus::Restricted {
+            AuthorizationStatus::Restricted
+        } else if status == EKAuthorizationStatus::Denied {
+            AuthorizationStatus::Denied
+        } else if status == EKAuthorizationStatus::FullAccess {
+            AuthorizationStatus::FullAccess
+        } else if status == EKAuthorizationStatus::WriteOnly {
+            AuthorizationStatus::WriteOnly
+        } else {
+            AuthorizationStatus::NotDetermined
         }
+    }
+}
 
-        Err(EventKitError::CalendarNotFound(title.to_string()))
+// Helper to map an authorization status to the error a caller should see
+fn authorization_result(status: AuthorizationStatus) -> Result<()> {
+    match status {
+        AuthorizationStatus::FullAccess => Ok(()),
+        // Can still read with write-only in some cases
+        AuthorizationStatus::WriteOnly => Ok(()),
+        AuthorizationStatus::NotDetermined => Err(EventKitError::AuthorizationNotDetermined),
+        AuthorizationStatus::Denied => Err(EventKitError::AuthorizationDenied),
+        AuthorizationStatus::Restricted => Err(EventKitError::AuthorizationRestricted),
     }
+}
 
-    // Helper to find an event by identifier
-    fn find_event_by_id(&self, identifier: &str) -> Result<Retained<EKEvent>> {
-        let ns_id = NSString::from_str(identifier);
-        let event = unsafe { self.store.eventWithIdentifier(&ns_id) };
+impl std::fmt::Display for AuthorizationStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AuthorizationStatus::NotDetermined => write!(f, "Not Determined"),
+            AuthorizationStatus::Restricted => write!(f, "Restricted"),
+            AuthorizationStatus::Denied => write!(f, "Denied"),
+            AuthorizationStatus::FullAccess => write!(f, "Full Access"),
+            AuthorizationStatus::WriteOnly => write!(f, "Write Only"),
+        }
+    }
+}
 
-        match event {
-            Some(e) => Ok(e),
-            None => Err(EventKitError::ItemNotFound(identifier.to_string())),
+/// The outcome of a permission request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccessResult {
+    /// Whether access was granted
+    pub granted: bool,
+    /// The authorization status after the request
+    ///
+    /// When access wasn't granted this says why: `Denied` if the user
+    /// declined, `Restricted` if a device policy blocks it.
+    pub status: AuthorizationStatus,
+}
+
+impl AccessResult {
+    // Helper to turn the outcome into the error `ensure_authorized` reports
+    fn into_result(self) -> Result<()> {
+        if self.granted {
+            Ok(())
+        } else if self.status == AuthorizationStatus::Restricted {
+            Err(EventKitError::AuthorizationRestricted)
+        } else {
+            Err(EventKitError::AuthorizationDenied)
         }
     }
 }
 
-impl Default for EventsManager {
-    fn default() -> Self {
-        Self::new()
+/// Authorization state for both entity types, for onboarding screens
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AuthorizationSummary {
+    /// Current reminders authorization
+    pub reminders: AuthorizationStatus,
+    /// Current calendar events authorization
+    pub events: AuthorizationStatus,
+    /// Whether this macOS version has the separate full-access permission
+    /// (macOS 14 and later); older versions only grant plain access
+    pub supports_full_access: bool,
+}
+
+/// Gets reminders and events authorization in one call, without prompting
+pub fn authorization_summary() -> AuthorizationSummary {
+    AuthorizationSummary {
+        reminders: RemindersManager::authorization_status(),
+        events: EventsManager::authorization_status(),
+        supports_full_access: supports_full_access(),
     }
 }
 
-// Helper function to convert EKEvent to EventItem
-fn event_to_item(event: &EKEvent) -> EventItem {
-    let identifier = unsafe { event.eventIdentifier() }
-        .map(|s| s.to_string())
-        .unwrap_or_default();
-    let title = unsafe { event.title() }.to_string();
-    let notes = unsafe { event.notes() }.map(|n| n.to_string());
-    let location = unsafe { event.location() }.map(|l| l.to_string());
-    let all_day = unsafe { event.isAllDay() };
-    let calendar_title = unsafe { event.calendar() }.map(|c| unsafe { c.title() }.to_string());
+// Helper to read the running macOS version as (major, minor, patch)
+fn os_version() -> (isize, isize, isize) {
+    let version = NSProcessInfo::processInfo().operatingSystemVersion();
+    (
+        version.majorVersion,
+        version.minorVersion,
+        version.patchVersion,
+    )
+}
 
-    let start_ns: Retained<NSDate> = unsafe { event.startDate() };
-    let end_ns: Retained<NSDate> = unsafe { event.endDate() };
+// Helper to format an OS version, leaving out a zero patch number
+fn format_os_version((major, minor, patch): (isize, isize, isize)) -> String {
+    if patch == 0 {
+        format!("{}.{}", major, minor)
+    } else {
+        format!("{}.{}.{}", major, minor, patch)
+    }
+}
 
-    let start_date = nsdate_to_datetime(&start_ns);
-    let end_date = nsdate_to_datetime(&end_ns);
+// Helper to check for the macOS 14 full-access request APIs
+fn supports_full_access() -> bool {
+    os_version().0 >= 14
+}
 
-    EventItem {
+// Helper function to convert EKReminder to ReminderItem
+fn reminder_to_item(reminder: &EKReminder) -> ReminderItem {
+    let identifier = ReminderId::new(unsafe { reminder.calendarItemIdentifier() }.to_string());
+    let external_identifier =
+        unsafe { reminder.calendarItemExternalIdentifier() }.map(|s| s.to_string());
+    let title = item_title(reminder);
+    let notes = unsafe { reminder.notes() }.map(|n| n.to_string());
+    let completed = unsafe { reminder.isCompleted() };
+    let completion_date = unsafe { reminder.completionDate() }.map(|d| nsdate_to_datetime(&d));
+    let creation_date = unsafe { reminder.creationDate() }.map(|d| nsdate_to_datetime(&d));
+    let last_modified_date = unsafe { reminder.lastModifiedDate() }.map(|d| nsdate_to_datetime(&d));
+    let priority = Priority::from(u8::try_from(unsafe { reminder.priority() }).unwrap_or(u8::MAX));
+    let calendar = unsafe { reminder.calendar() };
+    let calendar_title = calendar.as_ref().map(|c| unsafe { c.title() }.to_string());
+    let calendar_identifier = calendar
+        .as_ref()
+        .map(|c| CalendarId::new(unsafe { c.calendarIdentifier() }.to_string()));
+    let url = unsafe { reminder.URL() }
+        .and_then(|u| u.absoluteString())
+        .map(|u| u.to_string());
+    let alarms = alarms_from_item(reminder);
+    let due_date = unsafe { reminder.dueDateComponents() }.and_then(|c| components_to_datetime(&c));
+    let recurrence_rules = unsafe { reminder.recurrenceRules() }
+        .map(|rules| {
+            rules
+                .iter()
+                .filter_map(|r| recurrence_from_ek(&r))
+                .collect()
+        })
+        .unwrap_or_default();
+
+    ReminderItem {
         identifier,
+        external_identifier,
         title,
         notes,
-        location,
-        start_date,
-        end_date,
-        all_day,
+        completed,
+        completion_date,
+        creation_date,
+        last_modified_date,
+        priority,
         calendar_title,
+        calendar_identifier,
+        url,
+        alarms,
+        due_date,
+        recurrence_rules,
     }
 }
 
-// Helper to convert chrono DateTime to NSDate
-fn datetime_to_nsdate(dt: DateTime<Local>) -> Retained<NSDate> {
-    let timestamp = dt.timestamp() as f64;
-    NSDate::dateWithTimeIntervalSince1970(timestamp)
+// Helper to read an item's title, treating nil as an empty string.
+// The bindings declare `title` non-null, but imported or partially synced
+// items can still return nil, so it's read through an optional message send.
+fn item_title(item: &EKCalendarItem) -> String {
+    let title: Option<Retained<NSString>> = unsafe { msg_send![item, title] };
+    title_or_empty(title)
 }
 
-// Helper to convert NSDate to chrono DateTime
-fn nsdate_to_datetime(date: &NSDate) -> DateTime<Local> {
-    let timestamp = date.timeIntervalSince1970();
-    Local.timestamp_opt(timestamp as i64, 0).unwrap()
+fn title_or_empty(title: Option<Retained<NSString>>) -> String {
+    title.map(|t| t.to_string()).unwrap_or_default()
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+// Helper to reject relative alarms on a reminder with no due date to anchor
+// them, and geofences that could never fire
+fn check_reminder_alarms(alarms: &[Alarm], has_due_date: bool, title: &str) -> Result<()> {
+    check_alarm_locations(alarms)?;
+    let relative = alarms.iter().any(|a| matches!(a, Alarm::Relative(_)));
+    if relative && !has_due_date {
+        Err(EventKitError::NoDueDate(title.to_string()))
+    } else {
+        Ok(())
+    }
+}
 
-    #[test]
-    fn test_authorization_status_display() {
-        assert_eq!(
-            format!("{}", AuthorizationStatus::NotDetermined),
-            "Not Determined"
-        );
-        assert_eq!(
-            format!("{}", AuthorizationStatus::FullAccess),
-            "Full Access"
-        );
+// Helper to reject coordinates off the globe; EventKit saves them silently
+fn check_location(location: &StructuredLocation) -> Result<()> {
+    let valid = (-90.0..=90.0).contains(&location.latitude)
+        && (-180.0..=180.0).contains(&location.longitude)
+        && location.radius >= 0.0
+        && location.radius.is_finite();
+    if valid {
+        Ok(())
+    } else {
+        Err(EventKitError::InvalidLocation(location.title.clone()))
     }
+}
 
-    #[test]
-    fn test_event_item_debug() {
-        let event = EventItem {
-            identifier: "test".to_string(),
-            title: "Test Event".to_string(),
-            notes: None,
-            location: None,
-            start_date: Local::now(),
-            end_date: Local::now(),
-            all_day: false,
-            calendar_title: None,
-        };
-        assert!(format!("{:?}", event).contains("Test Event"));
+// Helper to reject geofences that could never fire
+fn check_alarm_locations(alarms: &[Alarm]) -> Result<()> {
+    for alarm in alarms {
+        if let Alarm::Proximity { location, .. } = alarm {
+            check_location(location)?;
+        }
+    }
+    Ok(())
+}
+
+// Helper to reject priorities outside EventKit's 0-9 range
+fn check_priority(priority: Priority) -> Result<()> {
+    if priority.value() > 9 {
+        return Err(EventKitError::InvalidPriority(priority.value().into()));
+    }
+    Ok(())
+}
+
+// Helper to look up an IANA time zone name
+fn parse_time_zone(name: &str) -> Result<Retained<NSTimeZone>> {
+    NSTimeZone::timeZoneWithName(&NSString::from_str(name))
+        .ok_or_else(|| EventKitError::InvalidTimeZone(name.to_string()))
+}
+
+// Helper to find a named zone's UTC offset at a given instant
+fn zone_offset(name: &str, instant: DateTime<Local>) -> Option<FixedOffset> {
+    let zone = parse_time_zone(name).ok()?;
+    let seconds = zone.secondsFromGMTForDate(&datetime_to_nsdate(instant));
+    FixedOffset::east_opt(seconds as i32)
+}
+
+// Helper to parse an absolute URL string into an NSURL
+fn parse_url(url: &str) -> Result<Retained<NSURL>> {
+    NSURL::URLWithString(&NSString::from_str(url))
+        .filter(|u| u.scheme().is_some())
+        .ok_or_else(|| EventKitError::InvalidUrl(url.to_string()))
+}
+
+// Helper to check a calendar can hold the given kind of item before saving into it
+fn ensure_calendar_allows(calendar: &EKCalendar, expected: EntityType) -> Result<()> {
+    let title = unsafe { calendar.title() }.to_string();
+    let allowed = unsafe { calendar.allowedEntityTypes() };
+    check_entity_type(&title, allowed, expected)
+}
+
+// Helper to tell whether new items of the given kind can be saved into a calendar
+fn is_writable(calendar: &EKCalendar, expected: EntityType) -> bool {
+    let modifiable = unsafe { calendar.allowsContentModifications() };
+    modifiable && ensure_calendar_allows(calendar, expected).is_ok()
+}
+
+fn check_entity_type(title: &str, allowed: EKEntityMask, expected: EntityType) -> Result<()> {
+    let required = match expected {
+        EntityType::Event => EKEntityMask::Event,
+        EntityType::Reminder => EKEntityMask::Reminder,
+    };
+
+    if allowed.contains(required) {
+        Ok(())
+    } else {
+        Err(EventKitError::CalendarWrongType {
+            title: title.to_string(),
+            expected,
+        })
+    }
+}
+
+// Helper to run check_availability against an event's calendar, if it has one
+fn check_calendar_availability(
+    calendar: Option<&EKCalendar>,
+    availability: EventAvailability,
+) -> Result<EKEventAvailability> {
+    let (title, supported) = match calendar {
+        Some(calendar) => (unsafe { calendar.title() }.to_string(), unsafe {
+            calendar.supportedEventAvailabilities()
+        }),
+        None => (String::new(), EKCalendarEventAvailabilityMask::None),
+    };
+    check_availability(&title, supported, availability)
+}
+
+// Helper to check a calendar supports an availability, returning EventKit's value for it
+fn check_availability(
+    calendar: &str,
+    supported: EKCalendarEventAvailabilityMask,
+    availability: EventAvailability,
+) -> Result<EKEventAvailability> {
+    let (ek, required) = match availability {
+        EventAvailability::Busy => (
+            EKEventAvailability::Busy,
+            EKCalendarEventAvailabilityMask::Busy,
+        ),
+        EventAvailability::Free => (
+            EKEventAvailability::Free,
+            EKCalendarEventAvailabilityMask::Free,
+        ),
+        EventAvailability::Tentative => (
+            EKEventAvailability::Tentative,
+            EKCalendarEventAvailabilityMask::Tentative,
+        ),
+        EventAvailability::Unavailable => (
+            EKEventAvailability::Unavailable,
+            EKCalendarEventAvailabilityMask::Unavailable,
+        ),
+        EventAvailability::NotSupported => (
+            EKEventAvailability::NotSupported,
+            EKCalendarEventAvailabilityMask::None,
+        ),
+    };
+
+    if !required.is_empty() && supported.contains(required) {
+        Ok(ek)
+    } else {
+        Err(EventKitError::AvailabilityUnsupported {
+            calendar: calendar.to_string(),
+            availability,
+        })
+    }
+}
+
+// Helper function to convert EKCalendar to CalendarInfo
+fn calendar_to_info(calendar: &EKCalendar) -> CalendarInfo {
+    let identifier = CalendarId::new(unsafe { calendar.calendarIdentifier() }.to_string());
+    let title = unsafe { calendar.title() }.to_string();
+    let source = unsafe { calendar.source() }.map(|s| unsafe { s.title() }.to_string());
+    let allows_modifications = unsafe { calendar.allowsContentModifications() };
+    let calendar_type = unsafe { calendar.r#type() }.into();
+    let color = unsafe { calendar.CGColor() }.and_then(|c| color_from_cg(&c));
+
+    CalendarInfo {
+        identifier,
+        title,
+        source,
+        allows_modifications,
+        calendar_type,
+        color,
+    }
+}
+
+// Helper to check whether an EventKit account is the one asked for. iCloud is
+// a CalDAV account titled "iCloud"; it was MobileMe on older systems.
+fn source_matches(wanted: &CalendarSource, title: &str, source_type: EKSourceType) -> bool {
+    match wanted {
+        CalendarSource::Default => false,
+        CalendarSource::ICloud => {
+            source_type == EKSourceType::MobileMe
+                || (source_type == EKSourceType::CalDAV && title.eq_ignore_ascii_case("iCloud"))
+        }
+        CalendarSource::Local => source_type == EKSourceType::Local,
+        CalendarSource::Named(name) => title.eq_ignore_ascii_case(name),
+    }
+}
+
+// Helper to convert a CGColor to CalendarColor. Grayscale colors have one
+// component plus alpha; anything else is read as RGB(A).
+fn color_from_cg(color: &CGColor) -> Option<CalendarColor> {
+    let count = CGColor::number_of_components(Some(color));
+    let components = CGColor::components(Some(color));
+    if components.is_null() || count < 2 {
+        return None;
+    }
+    let components = unsafe { std::slice::from_raw_parts(components, count) };
+    let (red, green, blue) = if count < 4 {
+        (components[0], components[0], components[0])
+    } else {
+        (components[0], components[1], components[2])
+    };
+    Some(color_from_components(red, green, blue))
+}
+
+fn color_from_components(red: f64, green: f64, blue: f64) -> CalendarColor {
+    let channel = |value: f64| (value.clamp(0.0, 1.0) * 255.0).round() as u8;
+    CalendarColor {
+        red: channel(red),
+        green: channel(green),
+        blue: channel(blue),
+    }
+}
+
+// ============================================================================
+// Calendar Events Support
+// ============================================================================
+
+/// Represents a calendar event with its properties
+#[derive(Debug, Clone)]
+pub struct EventItem {
+    /// Identifier for the event
+    ///
+    /// Stable on this device, but it can change after a full sync. Use it for
+    /// short-lived references such as a follow-up update or delete.
+    ///
+    /// **Every occurrence of a recurring event has the same identifier**, and
+    /// looking it up with [`EventsManager::get_event`] returns the series'
+    /// first occurrence. Use [`occurrence`](Self::occurrence) to address one
+    /// instance.
+    pub identifier: EventId,
+    /// Identifier from the calendar server, shared across devices
+    ///
+    /// Prefer this for references stored outside EventKit. It may be `None`
+    /// before the event first syncs, and it isn't unique: every occurrence of
+    /// a recurring event shares it, as do copies of an invitation.
+    pub external_identifier: Option<String>,
+    /// Title of the event (empty if the event has none)
+    pub title: String,
+    /// Optional notes/description
+    pub notes: Option<String>,
+    /// Whether the event has notes
+    pub has_notes: bool,
+    /// Optional location
+    pub location: Option<String>,
+    /// URL attached to the event (e.g. a video-call link)
+    pub url: Option<String>,
+    /// Start date/time
+    pub start_date: DateTime<Local>,
+    /// End date/time
+    pub end_date: DateTime<Local>,
+    /// Whether this is an all-day event
+    pub all_day: bool,
+    /// For an occurrence of a recurring event, the date this occurrence was
+    /// originally scheduled to start (even if it was later moved)
+    pub occurrence_date: Option<DateTime<Local>>,
+    /// Whether the event repeats
+    pub has_recurrence: bool,
+    /// Whether this occurrence was edited on its own, so it no longer matches
+    /// the rest of its series
+    ///
+    /// Check this before applying a series-wide change, or the user's edit to
+    /// this one occurrence may be overwritten.
+    pub is_detached: bool,
+    /// How the event repeats; empty if it doesn't
+    ///
+    /// Calendar lets an event carry several rules, in which case it occurs
+    /// whenever any of them does. See [`to_rrule_strings`](Self::to_rrule_strings)
+    /// for the RFC 5545 form.
+    pub recurrence_rules: Vec<RecurrenceRule>,
+    /// IANA name of the event's own time zone (e.g. "Asia/Tokyo")
+    ///
+    /// `None` for floating events, which happen at the same wall-clock time
+    /// in every zone. See [`start_in_event_zone`](Self::start_in_event_zone)
+    /// to show times the way the event's creator sees them.
+    pub time_zone: Option<String>,
+    /// Calendar the event belongs to
+    pub calendar_title: Option<String>,
+    /// Identifier of the calendar the event belongs to
+    ///
+    /// Unlike the title this is unique, so prefer it for grouping events or
+    /// looking the calendar up again.
+    pub calendar_identifier: Option<CalendarId>,
+    /// Geocoded location, when the event's location has coordinates
+    ///
+    /// When this is `None`, `location` still carries any plain-text location.
+    pub structured_location: Option<StructuredLocation>,
+    /// Alarms attached to the event
+    pub alarms: Vec<Alarm>,
+    /// Whether the event has any alarms
+    ///
+    /// Cheap to read, so list views can show an indicator from this alone.
+    pub has_alarms: bool,
+    /// People invited to the event
+    ///
+    /// Read-only: EventKit on macOS can't add or change attendees, so this is
+    /// only populated when reading events.
+    pub attendees: Vec<Participant>,
+    /// Whether the event has any attendees
+    pub has_attendees: bool,
+    /// The current user's response to the invitation, if they're among the
+    /// attendees (read-only)
+    pub my_participation_status: Option<ParticipantStatus>,
+    /// Organizer of the event, if it's a meeting invitation (read-only)
+    pub organizer: Option<Participant>,
+    /// How the event shows up in free/busy lookups
+    pub availability: EventAvailability,
+    /// Confirmation status of the event (read-only)
+    pub status: EventStatus,
+    /// When the event was created, if EventKit knows
+    pub creation_date: Option<DateTime<Local>>,
+    /// When the event was last changed, if EventKit knows
+    pub last_modified_date: Option<DateTime<Local>>,
+}
+
+/// How an event affects the owner's free/busy time
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventAvailability {
+    /// The calendar doesn't support availability
+    NotSupported,
+    /// Time is blocked
+    Busy,
+    /// Time is shown as free
+    Free,
+    /// Time is tentatively blocked
+    Tentative,
+    /// The owner is out of office
+    Unavailable,
+}
+
+impl From<EKEventAvailability> for EventAvailability {
+    fn from(availability: EKEventAvailability) -> Self {
+        if availability == EKEventAvailability::Busy {
+            EventAvailability::Busy
+        } else if availability == EKEventAvailability::Free {
+            EventAvailability::Free
+        } else if availability == EKEventAvailability::Tentative {
+            EventAvailability::Tentative
+        } else if availability == EKEventAvailability::Unavailable {
+            EventAvailability::Unavailable
+        } else {
+            EventAvailability::NotSupported
+        }
+    }
+}
+
+impl std::fmt::Display for EventAvailability {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventAvailability::NotSupported => write!(f, "Not Supported"),
+            EventAvailability::Busy => write!(f, "Busy"),
+            EventAvailability::Free => write!(f, "Free"),
+            EventAvailability::Tentative => write!(f, "Tentative"),
+            EventAvailability::Unavailable => write!(f, "Unavailable"),
+        }
+    }
+}
+
+/// Confirmation status of an event
+///
+/// Read-only: the status comes from the organizer's calendar server, and
+/// EventKit offers no way to change it, so an event can't be canceled
+/// without deleting it. To mark your own time as tentative or free, use
+/// [`EventsManager::set_event_availability`] instead.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventStatus {
+    /// No status set (most events that aren't meetings)
+    None,
+    /// The event is confirmed
+    Confirmed,
+    /// The event is tentative
+    Tentative,
+    /// The event was canceled
+    Canceled,
+}
+
+impl From<EKEventStatus> for EventStatus {
+    fn from(status: EKEventStatus) -> Self {
+        if status == EKEventStatus::Confirmed {
+            EventStatus::Confirmed
+        } else if status == EKEventStatus::Tentative {
+            EventStatus::Tentative
+        } else if status == EKEventStatus::Canceled {
+            EventStatus::Canceled
+        } else {
+            EventStatus::None
+        }
+    }
+}
+
+impl std::fmt::Display for EventStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EventStatus::None => write!(f, "None"),
+            EventStatus::Confirmed => write!(f, "Confirmed"),
+            EventStatus::Tentative => write!(f, "Tentative"),
+            EventStatus::Canceled => write!(f, "Canceled"),
+        }
+    }
+}
+
+/// Criteria for dropping events from fetch results
+///
+/// The default filter keeps everything.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct EventFilter {
+    /// Drop all-day events
+    pub exclude_all_day: bool,
+    /// Drop events whose status is [`EventStatus::Canceled`]
+    pub exclude_canceled: bool,
+    /// Drop invitations the current user has declined
+    pub exclude_declined: bool,
+    /// Keep only events that block time ([`EventAvailability::Busy`] or
+    /// [`EventAvailability::Unavailable`], or calendars without availability)
+    pub only_busy: bool,
+    /// How an event must sit within the fetched range to be kept
+    pub overlap: OverlapMode,
+}
+
+/// How an event must relate to a date range to be included in a fetch
+///
+/// EventKit itself only finds events that overlap the range, so the other
+/// modes are applied in Rust after fetching; they can only narrow the
+/// results, never add events outside the range.
+///
+/// All-day events are compared by date: one runs from its first day to its
+/// last, and the range from the day of `start` to the day of `end` (a range
+/// ending exactly at midnight doesn't include that day). So an all-day event
+/// today starts within a range that begins at 09:00 today.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OverlapMode {
+    /// Any event that overlaps the range, including ones that started
+    /// before it or end after it
+    #[default]
+    Overlaps,
+    /// Only events that start within the range
+    StartsWithin,
+    /// Only events that both start and end within the range
+    ContainedWithin,
+}
+
+impl OverlapMode {
+    /// Whether an event fits the range under this mode
+    pub fn includes(self, event: &EventItem, start: DateTime<Local>, end: DateTime<Local>) -> bool {
+        self.fits(event.start_date, event.end_date, event.all_day, start, end)
+    }
+
+    fn fits(
+        self,
+        event_start: DateTime<Local>,
+        event_end: DateTime<Local>,
+        all_day: bool,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> bool {
+        if all_day {
+            let (first, last) = (event_start.date_naive(), last_day(event_start, event_end));
+            let (range_first, range_last) = (start.date_naive(), last_day(start, end));
+            match self {
+                OverlapMode::Overlaps => first <= range_last && last >= range_first,
+                OverlapMode::StartsWithin => range_first <= first && first <= range_last,
+                OverlapMode::ContainedWithin => range_first <= first && last <= range_last,
+            }
+        } else {
+            match self {
+                OverlapMode::Overlaps => event_start < end && event_end > start,
+                OverlapMode::StartsWithin => start <= event_start && event_start < end,
+                OverlapMode::ContainedWithin => start <= event_start && event_end <= end,
+            }
+        }
+    }
+}
+
+impl EventFilter {
+    /// Whether an event passes this filter
+    ///
+    /// Ignores [`overlap`](Self::overlap), which needs the fetched range; see
+    /// [`matches_in_range`](Self::matches_in_range).
+    pub fn matches(&self, event: &EventItem) -> bool {
+        self.allows(event.all_day, event.status, event.availability)
+            && !(self.exclude_declined
+                && event.my_participation_status == Some(ParticipantStatus::Declined))
+    }
+
+    /// Whether an event fetched for `start..end` passes this filter
+    pub fn matches_in_range(
+        &self,
+        event: &EventItem,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> bool {
+        self.matches(event) && self.overlap.includes(event, start, end)
+    }
+
+    // Helper to check an EKEvent without converting it to an EventItem first
+    fn matches_ek(&self, event: &EKEvent, start: DateTime<Local>, end: DateTime<Local>) -> bool {
+        let all_day = unsafe { event.isAllDay() };
+        if !self.allows(
+            all_day,
+            unsafe { event.status() }.into(),
+            unsafe { event.availability() }.into(),
+        ) {
+            return false;
+        }
+        if self.exclude_declined && declined_by_current_user(event) {
+            return false;
+        }
+        // Overlapping is what EventKit already fetched, so skip the date reads
+        if self.overlap == OverlapMode::Overlaps {
+            return true;
+        }
+        let event_start: Retained<NSDate> = unsafe { event.startDate() };
+        let event_end: Retained<NSDate> = unsafe { event.endDate() };
+        self.overlap.fits(
+            nsdate_to_datetime(&event_start),
+            nsdate_to_datetime(&event_end),
+            all_day,
+            start,
+            end,
+        )
+    }
+
+    fn allows(&self, all_day: bool, status: EventStatus, availability: EventAvailability) -> bool {
+        if self.exclude_all_day && all_day {
+            return false;
+        }
+        if self.exclude_canceled && status == EventStatus::Canceled {
+            return false;
+        }
+        if self.only_busy {
+            return matches!(
+                availability,
+                EventAvailability::Busy
+                    | EventAvailability::Unavailable
+                    | EventAvailability::NotSupported
+            );
+        }
+        true
+    }
+}
+
+/// An event fetch assembled step by step
+///
+/// ```no_run
+/// # use chrono::{Duration, Local};
+/// # use eventkit::{EventQuery, EventsManager, SortOrder};
+/// # let manager = EventsManager::new();
+/// let now = Local::now();
+/// let meetings = EventQuery::new()
+///     .between(now, now + Duration::days(30))
+///     .calendars(["Work"])
+///     .search("review")
+///     .exclude_all_day()
+///     .exclude_declined()
+///     .sort_by(SortOrder::StartAsc)
+///     .limit(10)
+///     .fetch(&manager)?;
+/// # Ok::<(), eventkit::EventKitError>(())
+/// ```
+///
+/// EventKit can only fetch events within a date range, so
+/// [`between`](Self::between) is required. Without
+/// [`sort_by`](Self::sort_by), results are sorted by start date.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EventQuery {
+    range: Option<(DateTime<Local>, DateTime<Local>)>,
+    calendar_titles: Option<Vec<String>>,
+    text: Option<String>,
+    filter: EventFilter,
+    sort: Option<SortOrder>,
+    limit: Option<usize>,
+}
+
+impl EventQuery {
+    /// Creates a query with no range or criteria yet
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only events overlapping `start..end`
+    pub fn between(mut self, start: DateTime<Local>, end: DateTime<Local>) -> Self {
+        self.range = Some((start, end));
+        self
+    }
+
+    /// Only events in calendars with these titles
+    pub fn calendars<I, S>(mut self, titles: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.calendar_titles = Some(titles.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Only events whose title, notes or location contain `text`, ignoring
+    /// case
+    pub fn search(mut self, text: impl Into<String>) -> Self {
+        self.text = Some(text.into().to_lowercase());
+        self
+    }
+
+    /// Leaves out all-day events
+    pub fn exclude_all_day(mut self) -> Self {
+        self.filter.exclude_all_day = true;
+        self
+    }
+
+    /// Leaves out invitations the current user has declined
+    pub fn exclude_declined(mut self) -> Self {
+        self.filter.exclude_declined = true;
+        self
+    }
+
+    /// Sorts the results in this order
+    pub fn sort_by(mut self, sort: SortOrder) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    /// Keeps at most `limit` events, after sorting
+    pub fn limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Whether an event passes this query's criteria
+    pub fn matches(&self, event: &EventItem) -> bool {
+        let in_range = self
+            .range
+            .is_none_or(|(start, end)| self.filter.overlap.includes(event, start, end));
+        let in_calendar = match (&self.calendar_titles, &event.calendar_title) {
+            (None, _) => true,
+            (Some(titles), Some(title)) => titles.contains(title),
+            (Some(_), None) => false,
+        };
+        let has_text = self.text.as_ref().is_none_or(|text| {
+            text_matches(
+                text,
+                &event.title,
+                event.notes.as_deref(),
+                event.location.as_deref(),
+            )
+        });
+
+        self.filter.matches(event) && in_range && in_calendar && has_text
+    }
+
+    /// Runs the query
+    ///
+    /// Returns [`EventKitError::InvalidDateRange`] if
+    /// [`between`](Self::between) wasn't called or its range is empty.
+    pub fn fetch(&self, manager: &EventsManager) -> Result<Vec<EventItem>> {
+        manager.fetch_query(self)
+    }
+}
+
+/// Handle for one occurrence of a recurring event
+///
+/// The identifier alone can't tell occurrences apart, so this pairs it with
+/// the occurrence's original start date.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct EventOccurrence {
+    /// Identifier shared by the whole series
+    pub identifier: EventId,
+    /// Original start date of this occurrence
+    pub occurrence_date: DateTime<Local>,
+}
+
+/// Which occurrences of a recurring event a change or deletion affects
+///
+/// For events that don't repeat, both spans behave the same.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Span {
+    /// Only the given occurrence
+    #[default]
+    ThisEvent,
+    /// The given occurrence and every later one; from the first occurrence,
+    /// that's the whole series
+    ThisAndFuture,
+}
+
+impl From<Span> for EKSpan {
+    fn from(span: Span) -> Self {
+        match span {
+            Span::ThisEvent => EKSpan::ThisEvent,
+            Span::ThisAndFuture => EKSpan::FutureEvents,
+        }
+    }
+}
+
+/// A span of time blocked by one or more events
+///
+/// Returned by [`EventsManager::free_busy`]; blocks never overlap or touch,
+/// since overlapping and back-to-back events are merged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BusyBlock {
+    /// When the block starts
+    pub start: DateTime<Local>,
+    /// When the block ends
+    pub end: DateTime<Local>,
+}
+
+/// Daily window that free slots must fall within
+///
+/// Used by [`EventsManager::find_free_slots`]. Times are wall-clock times in
+/// the local time zone; `end` must be after `start`, so windows can't span
+/// midnight.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WorkingHours {
+    /// When the working day starts
+    pub start: NaiveTime,
+    /// When the working day ends
+    pub end: NaiveTime,
+    /// Whether Saturdays and Sundays are working days
+    pub weekends: bool,
+}
+
+impl Default for WorkingHours {
+    /// 09:00 to 17:00, Monday to Friday
+    fn default() -> Self {
+        Self {
+            start: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end: NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            weekends: false,
+        }
+    }
+}
+
+/// Events grouped by day, as returned by [`EventsManager::agenda`]
+#[derive(Debug, Clone, Default)]
+pub struct Agenda {
+    /// Days that have at least one event, in order
+    pub days: Vec<AgendaDay>,
+}
+
+/// One day of an [`Agenda`]
+#[derive(Debug, Clone)]
+pub struct AgendaDay {
+    /// The day
+    pub date: NaiveDate,
+    /// All-day events on this day
+    pub all_day: Vec<EventItem>,
+    /// Timed events starting on this day
+    pub timed: Vec<EventItem>,
+}
+
+impl Agenda {
+    /// Groups events by the day they start on
+    ///
+    /// Days appear in the order of their first event, and events keep their
+    /// order within each day, so sorting the input (see [`SortOrder`])
+    /// sorts the agenda. Multi-day events are listed on their first day only.
+    pub fn from_events(events: Vec<EventItem>) -> Self {
+        group_agenda(events, 0, None)
+    }
+
+    /// Total number of events across all days
+    pub fn len(&self) -> usize {
+        self.days
+            .iter()
+            .map(|d| d.all_day.len() + d.timed.len())
+            .sum()
+    }
+
+    /// Whether the agenda has no events
+    pub fn is_empty(&self) -> bool {
+        self.days.is_empty()
+    }
+}
+
+impl EventItem {
+    /// The event's recurrence rules as RFC 5545 `RRULE:` lines
+    pub fn to_rrule_strings(&self) -> Vec<String> {
+        self.recurrence_rules.iter().map(|r| r.to_rrule()).collect()
+    }
+
+    /// First and last day (inclusive) of an all-day event, or `None` for a
+    /// timed one
+    ///
+    /// Prefer this to the start and end timestamps for all-day events: the
+    /// days stay the same whatever the time zone or DST rules.
+    pub fn all_day_dates(&self) -> Option<(NaiveDate, NaiveDate)> {
+        self.all_day.then(|| {
+            (
+                self.start_date.date_naive(),
+                last_day(self.start_date, self.end_date),
+            )
+        })
+    }
+
+    /// How the event repeats, in words (e.g. "every 2 weeks on Mon, Wed,
+    /// until 2025-06-01"), or `None` if it doesn't
+    ///
+    /// Several rules are joined with "; ". A repeating event whose rules
+    /// couldn't be read is summarized as just "repeats".
+    pub fn recurrence_summary(&self) -> Option<String> {
+        if !self.has_recurrence {
+            return None;
+        }
+        if self.recurrence_rules.is_empty() {
+            return Some("repeats".to_string());
+        }
+        let rules: Vec<String> = self
+            .recurrence_rules
+            .iter()
+            .map(|r| r.to_string())
+            .collect();
+        Some(rules.join("; "))
+    }
+
+    /// Handle for this specific occurrence, if the event recurs
+    pub fn occurrence(&self) -> Option<EventOccurrence> {
+        self.occurrence_date.map(|occurrence_date| EventOccurrence {
+            identifier: self.identifier.clone(),
+            occurrence_date,
+        })
+    }
+
+    /// Start date in the event's own time zone
+    ///
+    /// Falls back to the local zone for floating events and for zone names
+    /// this system doesn't recognize.
+    pub fn start_in_event_zone(&self) -> DateTime<FixedOffset> {
+        self.in_event_zone(self.start_date)
+    }
+
+    /// End date in the event's own time zone (see
+    /// [`start_in_event_zone`](Self::start_in_event_zone))
+    pub fn end_in_event_zone(&self) -> DateTime<FixedOffset> {
+        self.in_event_zone(self.end_date)
+    }
+
+    /// How long the event lasts
+    ///
+    /// For all-day events this is a whole number of days (see
+    /// [`day_count`](Self::day_count)), so a day that a DST change makes 23
+    /// or 25 hours long still counts as one day.
+    pub fn duration(&self) -> Duration {
+        if self.all_day {
+            Duration::days(self.day_count())
+        } else {
+            self.end_date - self.start_date
+        }
+    }
+
+    /// Number of calendar days the event covers
+    ///
+    /// An end at exactly midnight doesn't count the day it falls on, so an
+    /// all-day event ending at the start of the next day is one day long,
+    /// as is one EventKit reports ending at 23:59:59.
+    pub fn day_count(&self) -> i64 {
+        (last_day(self.start_date, self.end_date) - self.start_date.date_naive()).num_days() + 1
+    }
+
+    /// Whether the event has started but not yet ended at `now`
+    ///
+    /// Zero-length events are never ongoing.
+    pub fn is_ongoing(&self, now: DateTime<Local>) -> bool {
+        self.start_date <= now && now < self.end_date
+    }
+
+    /// Whether the event has ended by `now`
+    pub fn is_past(&self, now: DateTime<Local>) -> bool {
+        self.end_date <= now
+    }
+
+    /// Whether the event starts after `now`
+    pub fn is_future(&self, now: DateTime<Local>) -> bool {
+        self.start_date > now
+    }
+
+    fn in_event_zone(&self, instant: DateTime<Local>) -> DateTime<FixedOffset> {
+        match self
+            .time_zone
+            .as_deref()
+            .and_then(|name| zone_offset(name, instant))
+        {
+            Some(offset) => instant.with_timezone(&offset),
+            None => instant.fixed_offset(),
+        }
+    }
+}
+
+/// A person invited to, or organizing, an event
+#[derive(Debug, Clone, PartialEq)]
+pub struct Participant {
+    /// Display name, if known
+    pub name: Option<String>,
+    /// Email address, when the participant's URL is a `mailto:` link
+    pub email: Option<String>,
+    /// URL identifying the participant (usually `mailto:`)
+    pub url: Option<String>,
+    /// The participant's role in the event
+    pub role: ParticipantRole,
+    /// The participant's response to the invitation
+    pub status: ParticipantStatus,
+    /// Whether this participant is the current user
+    pub is_current_user: bool,
+}
+
+/// Role of a participant in an event
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticipantRole {
+    /// Role not known
+    Unknown,
+    /// Attendance is required
+    Required,
+    /// Attendance is optional
+    Optional,
+    /// Chairs the meeting
+    Chair,
+    /// Receives the invitation for information only
+    NonParticipant,
+}
+
+impl From<EKParticipantRole> for ParticipantRole {
+    fn from(role: EKParticipantRole) -> Self {
+        if role == EKParticipantRole::Required {
+            ParticipantRole::Required
+        } else if role == EKParticipantRole::Optional {
+            ParticipantRole::Optional
+        } else if role == EKParticipantRole::Chair {
+            ParticipantRole::Chair
+        } else if role == EKParticipantRole::NonParticipant {
+            ParticipantRole::NonParticipant
+        } else {
+            ParticipantRole::Unknown
+        }
+    }
+}
+
+impl std::fmt::Display for ParticipantRole {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipantRole::Unknown => write!(f, "Unknown"),
+            ParticipantRole::Required => write!(f, "Required"),
+            ParticipantRole::Optional => write!(f, "Optional"),
+            ParticipantRole::Chair => write!(f, "Chair"),
+            ParticipantRole::NonParticipant => write!(f, "Non-Participant"),
+        }
+    }
+}
+
+/// A participant's response to an event invitation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParticipantStatus {
+    /// Response not known
+    Unknown,
+    /// No response yet
+    Pending,
+    /// Accepted the invitation
+    Accepted,
+    /// Declined the invitation
+    Declined,
+    /// Tentatively accepted
+    Tentative,
+    /// Delegated to someone else
+    Delegated,
+    /// Completed (for to-do style invitations)
+    Completed,
+    /// In process (for to-do style invitations)
+    InProcess,
+}
+
+impl From<EKParticipantStatus> for ParticipantStatus {
+    fn from(status: EKParticipantStatus) -> Self {
+        if status == EKParticipantStatus::Pending {
+            ParticipantStatus::Pending
+        } else if status == EKParticipantStatus::Accepted {
+            ParticipantStatus::Accepted
+        } else if status == EKParticipantStatus::Declined {
+            ParticipantStatus::Declined
+        } else if status == EKParticipantStatus::Tentative {
+            ParticipantStatus::Tentative
+        } else if status == EKParticipantStatus::Delegated {
+            ParticipantStatus::Delegated
+        } else if status == EKParticipantStatus::Completed {
+            ParticipantStatus::Completed
+        } else if status == EKParticipantStatus::InProcess {
+            ParticipantStatus::InProcess
+        } else {
+            ParticipantStatus::Unknown
+        }
+    }
+}
+
+impl std::fmt::Display for ParticipantStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParticipantStatus::Unknown => write!(f, "Unknown"),
+            ParticipantStatus::Pending => write!(f, "Pending"),
+            ParticipantStatus::Accepted => write!(f, "Accepted"),
+            ParticipantStatus::Declined => write!(f, "Declined"),
+            ParticipantStatus::Tentative => write!(f, "Tentative"),
+            ParticipantStatus::Delegated => write!(f, "Delegated"),
+            ParticipantStatus::Completed => write!(f, "Completed"),
+            ParticipantStatus::InProcess => write!(f, "In Process"),
+        }
+    }
+}
+
+/// A named location with geographic coordinates
+#[derive(Debug, Clone, PartialEq)]
+pub struct StructuredLocation {
+    /// Display title of the location (e.g. "Conference Room A")
+    pub title: String,
+    /// Latitude in degrees
+    pub latitude: f64,
+    /// Longitude in degrees
+    pub longitude: f64,
+    /// Radius in meters around the coordinates (0 uses EventKit's default)
+    pub radius: f64,
+}
+
+/// An alarm attached to an event or reminder
+#[derive(Debug, Clone, PartialEq)]
+pub enum Alarm {
+    /// Fires at an offset from the event's start (or the reminder's due date);
+    /// negative offsets fire before
+    Relative(Duration),
+    /// Fires at a fixed point in time
+    Absolute(DateTime<Local>),
+    /// Fires when the device arrives at or leaves a location
+    ///
+    /// EventKit rejects the save of a proximity alarm that has no structured
+    /// location, so one is always required here.
+    Proximity {
+        /// Whether to fire on arrival or departure
+        trigger: Proximity,
+        /// The geofenced location
+        location: StructuredLocation,
+    },
+}
+
+impl Alarm {
+    /// A proximity alarm that fires on arriving at `location`
+    pub fn on_arrival(location: StructuredLocation) -> Self {
+        Alarm::Proximity {
+            trigger: Proximity::Enter,
+            location,
+        }
+    }
+
+    /// A proximity alarm that fires on leaving `location`
+    pub fn on_departure(location: StructuredLocation) -> Self {
+        Alarm::Proximity {
+            trigger: Proximity::Leave,
+            location,
+        }
+    }
+}
+
+/// When a proximity alarm fires relative to its location
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Proximity {
+    /// Fires on arriving at the location
+    Enter,
+    /// Fires on leaving the location
+    Leave,
+}
+
+/// How a recurring item repeats
+///
+/// Mirrors an EventKit recurrence rule, which covers RFC 5545's FREQ,
+/// INTERVAL, COUNT/UNTIL, BYDAY, BYMONTHDAY, BYMONTH, BYWEEKNO, BYYEARDAY and
+/// BYSETPOS. As in EventKit, days of the month only apply to monthly rules;
+/// months, weeks and days of the year only to yearly ones.
+///
+/// [`to_rrule`](Self::to_rrule) and [`from_rrule`](Self::from_rrule)
+/// convert to and from the `RRULE:` text other calendar systems use.
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecurrenceRule {
+    /// The unit the rule repeats in
+    pub frequency: RecurrenceFrequency,
+    /// How many units between occurrences (2 with `Weekly` is every other week)
+    pub interval: usize,
+    /// When the repetition stops; `None` repeats forever
+    pub end: Option<RecurrenceEnd>,
+    /// Weekdays the rule falls on; empty means the start date's weekday
+    pub days_of_week: Vec<RecurrenceDay>,
+    /// Days of the month it falls on, 1 to 31 or -1 (the last) to -31
+    pub days_of_month: Vec<i64>,
+    /// Months it falls in, 1 (January) to 12
+    pub months_of_year: Vec<u32>,
+    /// Weeks of the year it falls in, 1 to 53 or -1 (the last) to -53
+    pub weeks_of_year: Vec<i64>,
+    /// Days of the year it falls on, 1 to 366 or -1 (the last) to -366
+    pub days_of_year: Vec<i64>,
+    /// Which of the days picked in each period to keep (1 is the first,
+    /// -1 the last), e.g. the last weekday of the month
+    pub set_positions: Vec<i64>,
+}
+
+/// A weekday a [`RecurrenceRule`] falls on, as in RRULE's BYDAY
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RecurrenceDay {
+    /// The day of the week
+    pub weekday: Weekday,
+    /// Which one within the month or year (1 is the first, -1 the last),
+    /// or 0 for every one
+    pub week: i64,
+}
+
+impl RecurrenceRule {
+    /// A rule repeating every `interval` units, forever, on no particular days
+    pub fn new(frequency: RecurrenceFrequency, interval: usize) -> Self {
+        Self {
+            frequency,
+            interval,
+            end: None,
+            days_of_week: Vec::new(),
+            days_of_month: Vec::new(),
+            months_of_year: Vec::new(),
+            weeks_of_year: Vec::new(),
+            days_of_year: Vec::new(),
+            set_positions: Vec::new(),
+        }
+    }
+
+    /// Whether the rule picks its days by anything other than weekdays
+    pub fn has_day_constraints(&self) -> bool {
+        !(self.days_of_month.is_empty()
+            && self.months_of_year.is_empty()
+            && self.weeks_of_year.is_empty()
+            && self.days_of_year.is_empty()
+            && self.set_positions.is_empty())
+    }
+
+    /// Formats the rule as an RFC 5545 `RRULE:` line
+    ///
+    /// `UNTIL` is written in UTC.
+    pub fn to_rrule(&self) -> String {
+        let freq = match self.frequency {
+            RecurrenceFrequency::Daily => "DAILY",
+            RecurrenceFrequency::Weekly => "WEEKLY",
+            RecurrenceFrequency::Monthly => "MONTHLY",
+            RecurrenceFrequency::Yearly => "YEARLY",
+        };
+        let mut rrule = format!("RRULE:FREQ={}", freq);
+        if self.interval != 1 {
+            rrule.push_str(&format!(";INTERVAL={}", self.interval));
+        }
+        match &self.end {
+            Some(RecurrenceEnd::Count(n)) => rrule.push_str(&format!(";COUNT={}", n)),
+            Some(RecurrenceEnd::Until(date)) => rrule.push_str(&format!(
+                ";UNTIL={}",
+                date.with_timezone(&chrono::Utc).format("%Y%m%dT%H%M%SZ")
+            )),
+            None => {}
+        }
+        if !self.days_of_week.is_empty() {
+            let days: Vec<String> = self
+                .days_of_week
+                .iter()
+                .map(|day| {
+                    let code = rrule_weekday_code(day.weekday);
+                    if day.week == 0 {
+                        code.to_string()
+                    } else {
+                        format!("{}{}", day.week, code)
+                    }
+                })
+                .collect();
+            rrule.push_str(&format!(";BYDAY={}", days.join(",")));
+        }
+        let months: Vec<i64> = self.months_of_year.iter().map(|&m| m as i64).collect();
+        for (name, values) in [
+            ("BYMONTHDAY", &self.days_of_month),
+            ("BYYEARDAY", &self.days_of_year),
+            ("BYWEEKNO", &self.weeks_of_year),
+            ("BYMONTH", &months),
+            ("BYSETPOS", &self.set_positions),
+        ] {
+            if !values.is_empty() {
+                let values: Vec<String> = values.iter().map(i64::to_string).collect();
+                rrule.push_str(&format!(";{}={}", name, values.join(",")));
+            }
+        }
+        rrule
+    }
+
+    /// Parses an RFC 5545 recurrence rule, with or without the `RRULE:` prefix
+    ///
+    /// Understands FREQ (daily to yearly), INTERVAL, COUNT, UNTIL, BYDAY,
+    /// BYMONTHDAY, BYMONTH, BYWEEKNO, BYYEARDAY and BYSETPOS, and ignores
+    /// WKST. A date-only UNTIL runs to the end of that day, and one without
+    /// a trailing `Z` is read as local time. Any other part, or one EventKit
+    /// can't apply to the rule's frequency (BYMONTH on a weekly rule, say),
+    /// returns [`EventKitError::InvalidRRule`] rather than being silently
+    /// dropped.
+    pub fn from_rrule(rrule: &str) -> Result<Self> {
+        let invalid = |why: String| EventKitError::InvalidRRule(why);
+        let body = rrule.trim();
+        let body = body.strip_prefix("RRULE:").unwrap_or(body);
+
+        let mut frequency = None;
+        let mut rule = RecurrenceRule::new(RecurrenceFrequency::Daily, 1);
+        for part in body.split(';').filter(|p| !p.is_empty()) {
+            let (key, value) = part
+                .split_once('=')
+                .ok_or_else(|| invalid(format!("'{}' isn't NAME=VALUE", part)))?;
+            match key.to_ascii_uppercase().as_str() {
+                "FREQ" => {
+                    frequency = Some(match value.to_ascii_uppercase().as_str() {
+                        "DAILY" => RecurrenceFrequency::Daily,
+                        "WEEKLY" => RecurrenceFrequency::Weekly,
+                        "MONTHLY" => RecurrenceFrequency::Monthly,
+                        "YEARLY" => RecurrenceFrequency::Yearly,
+                        _ => return Err(invalid(format!("unsupported FREQ '{}'", value))),
+                    })
+                }
+                "INTERVAL" => {
+                    rule.interval = value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| invalid(format!("bad INTERVAL '{}'", value)))?
+                }
+                "COUNT" | "UNTIL" if rule.end.is_some() => {
+                    return Err(invalid("COUNT and UNTIL can't both be given".to_string()));
+                }
+                "COUNT" => {
+                    let count = value
+                        .parse()
+                        .ok()
+                        .filter(|n| *n > 0)
+                        .ok_or_else(|| invalid(format!("bad COUNT '{}'", value)))?;
+                    rule.end = Some(RecurrenceEnd::Count(count));
+                }
+                "UNTIL" => {
+                    let until = parse_rrule_until(value)
+                        .ok_or_else(|| invalid(format!("bad UNTIL '{}'", value)))?;
+                    rule.end = Some(RecurrenceEnd::Until(until));
+                }
+                "BYDAY" => {
+                    rule.days_of_week = value
+                        .split(',')
+                        .map(|day| {
+                            parse_rrule_day(day)
+                                .ok_or_else(|| invalid(format!("bad BYDAY '{}'", day)))
+                        })
+                        .collect::<Result<_>>()?
+                }
+                "BYMONTHDAY" => rule.days_of_month = parse_rrule_numbers(key, value, 31)?,
+                "BYMONTH" => {
+                    let months = parse_rrule_numbers(key, value, 12)?;
+                    if months.iter().any(|m| *m < 0) {
+                        return Err(invalid(format!("bad BYMONTH '{}'", value)));
+                    }
+                    rule.months_of_year = months.into_iter().map(|m| m as u32).collect();
+                }
+                "BYWEEKNO" => rule.weeks_of_year = parse_rrule_numbers(key, value, 53)?,
+                "BYYEARDAY" => rule.days_of_year = parse_rrule_numbers(key, value, 366)?,
+                "BYSETPOS" => rule.set_positions = parse_rrule_numbers(key, value, 366)?,
+                "WKST" => {}
+                other => return Err(invalid(format!("{} isn't supported", other))),
+            }
+        }
+
+        rule.frequency = frequency.ok_or_else(|| invalid("missing FREQ".to_string()))?;
+        if !rule.days_of_month.is_empty() && rule.frequency != RecurrenceFrequency::Monthly {
+            return Err(invalid("BYMONTHDAY needs FREQ=MONTHLY".to_string()));
+        }
+        let yearly_only = !(rule.months_of_year.is_empty()
+            && rule.weeks_of_year.is_empty()
+            && rule.days_of_year.is_empty());
+        if yearly_only && rule.frequency != RecurrenceFrequency::Yearly {
+            return Err(invalid(
+                "BYMONTH, BYWEEKNO and BYYEARDAY need FREQ=YEARLY".to_string(),
+            ));
+        }
+        if !rule.set_positions.is_empty()
+            && (rule.frequency == RecurrenceFrequency::Daily || rule.days_of_week.is_empty())
+        {
+            return Err(invalid(
+                "BYSETPOS needs BYDAY and a weekly or longer FREQ".to_string(),
+            ));
+        }
+        Ok(rule)
+    }
+}
+
+/// The unit a [`RecurrenceRule`] repeats in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RecurrenceFrequency {
+    /// Repeats every `interval` days
+    Daily,
+    /// Repeats every `interval` weeks
+    Weekly,
+    /// Repeats every `interval` months
+    Monthly,
+    /// Repeats every `interval` years
+    Yearly,
+}
+
+/// When a [`RecurrenceRule`] stops repeating
+#[derive(Debug, Clone, PartialEq)]
+pub enum RecurrenceEnd {
+    /// After this many occurrences in total
+    Count(usize),
+    /// After this date
+    Until(DateTime<Local>),
+}
+
+impl std::str::FromStr for RecurrenceRule {
+    type Err = EventKitError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Self::from_rrule(s)
+    }
+}
+
+impl std::fmt::Display for RecurrenceRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let unit = match self.frequency {
+            RecurrenceFrequency::Daily => "day",
+            RecurrenceFrequency::Weekly => "week",
+            RecurrenceFrequency::Monthly => "month",
+            RecurrenceFrequency::Yearly => "year",
+        };
+        if self.interval == 1 {
+            write!(f, "every {}", unit)?;
+        } else {
+            write!(f, "every {} {}s", self.interval, unit)?;
+        }
+        if !self.days_of_week.is_empty() {
+            let days: Vec<String> = self
+                .days_of_week
+                .iter()
+                .map(|day| match day.week {
+                    0 => day.weekday.to_string(),
+                    week => format!("the {} {}", ordinal(week), day.weekday),
+                })
+                .collect();
+            write!(f, " on {}", days.join(", "))?;
+        }
+        let ordinals = |values: &[i64]| {
+            values
+                .iter()
+                .map(|&n| ordinal(n))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        if !self.days_of_month.is_empty() {
+            write!(f, " on the {} day", ordinals(&self.days_of_month))?;
+        }
+        if !self.days_of_year.is_empty() {
+            write!(
+                f,
+                " on the {} day of the year",
+                ordinals(&self.days_of_year)
+            )?;
+        }
+        if !self.weeks_of_year.is_empty() {
+            write!(f, " in the {} week", ordinals(&self.weeks_of_year))?;
+        }
+        if !self.months_of_year.is_empty() {
+            let months: Vec<&str> = self
+                .months_of_year
+                .iter()
+                .filter_map(|&m| chrono::Month::try_from(m as u8).ok())
+                .map(|m| m.name())
+                .collect();
+            write!(f, " in {}", months.join(", "))?;
+        }
+        if !self.set_positions.is_empty() {
+            write!(f, " (only the {})", ordinals(&self.set_positions))?;
+        }
+        match &self.end {
+            Some(RecurrenceEnd::Count(n)) => write!(f, ", {} times", n),
+            Some(RecurrenceEnd::Until(date)) => write!(f, ", until {}", date.format("%Y-%m-%d")),
+            None => Ok(()),
+        }
+    }
+}
+
+// Helper to spell out a BYDAY position: 1st, 2nd, ..., last, 2nd-to-last
+fn ordinal(n: i64) -> String {
+    if n == -1 {
+        return "last".to_string();
+    }
+    if n < 0 {
+        return format!("{}-to-last", ordinal(-n));
+    }
+    let suffix = match (n % 10, n % 100) {
+        (_, 11..=13) => "th",
+        (1, _) => "st",
+        (2, _) => "nd",
+        (3, _) => "rd",
+        _ => "th",
+    };
+    format!("{}{}", n, suffix)
+}
+
+// How far ahead `next_event` searches before giving up (EventKit caps a
+// single event predicate at four years)
+const NEXT_EVENT_HORIZON_DAYS: i64 = 4 * 365;
+
+// Longest range fetched with one event predicate; EventKit silently cuts
+// longer ranges off at four years, so they're split into chunks
+const PREDICATE_MAX_DAYS: i64 = 4 * 365;
+
+// How far either side of the new start `upsert_event` looks for the existing event
+const UPSERT_SEARCH_DAYS: i64 = 365;
+
+// URL scheme `upsert_event` uses to tag events with their external key
+const UPSERT_URL_SCHEME: &str = "eventkit-rs-key";
+
+/// The events manager providing access to Calendar events via EventKit
+///
+/// Like [`RemindersManager`], each instance caches its calendar handles; the
+/// cache is not shared between managers and is dropped whenever the event
+/// store reports a change.
+pub struct EventsManager {
+    store: Retained<EKEventStore>,
+    calendars: CalendarCache,
+    auto_refresh: bool,
+    day_start_hour: u32,
+    retry: RetryPolicy,
+    dry_run: bool,
+    fuzzy_titles: bool,
+    timeout: std::time::Duration,
+}
+
+impl EventsManager {
+    /// Creates a new EventsManager instance
+    pub fn new() -> Self {
+        Self::with_store(unsafe { EKEventStore::new() })
+    }
+
+    /// Creates a manager on an existing `EKEventStore`
+    ///
+    /// Lets code that talks to EventKit through objc2 directly share one store
+    /// with this crate. Requires the `raw-store` feature; like
+    /// [`raw_store`](Self::raw_store), this is an escape hatch with no
+    /// stability guarantees.
+    #[cfg(feature = "raw-store")]
+    pub fn from_store(store: Retained<EKEventStore>) -> Self {
+        Self::with_store(store)
+    }
+
+    /// Borrows the underlying `EKEventStore`
+    ///
+    /// An escape hatch for reading properties this crate doesn't wrap yet.
+    /// Requires the `raw-store` feature and carries no stability guarantees:
+    /// it may change or go away whenever the wrappers catch up.
+    ///
+    /// # Safety
+    ///
+    /// The manager assumes it is the only thing changing the store. Saving,
+    /// removing, committing or resetting through the returned store bypasses
+    /// dry-run mode and the retry policy, and can leave this manager's cached
+    /// calendars out of date until the next change notification.
+    #[cfg(feature = "raw-store")]
+    pub unsafe fn raw_store(&self) -> &EKEventStore {
+        &self.store
+    }
+
+    // Helper to create a manager on an existing (possibly shared) store
+    fn with_store(store: Retained<EKEventStore>) -> Self {
+        let calendars = CalendarCache::new(&store, EKEntityType::Event);
+        Self {
+            store,
+            calendars,
+            auto_refresh: false,
+            day_start_hour: 0,
+            retry: RetryPolicy::default(),
+            dry_run: false,
+            fuzzy_titles: false,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    /// Asks EventKit to sync remote (iCloud/CalDAV) sources if they're stale
+    ///
+    /// This only starts the sync: changes made on other devices may still
+    /// arrive after it returns, so a fetch made right away can miss them.
+    pub fn refresh(&self) {
+        unsafe { self.store.refreshSourcesIfNecessary() };
+    }
+
+    /// Makes every fetch call [`refresh`](Self::refresh) first
+    ///
+    /// Off by default. Turning it on narrows the window for stale results at
+    /// the cost of extra latency on each fetch while sources are checked.
+    pub fn set_auto_refresh(&mut self, enabled: bool) {
+        self.auto_refresh = enabled;
+    }
+
+    /// Sets how saves are retried when they fail with a transient error
+    ///
+    /// Defaults to no retries. See [`RetryPolicy`] for which errors count as
+    /// transient.
+    pub fn set_retry_policy(&mut self, policy: RetryPolicy) {
+        self.retry = policy;
+    }
+
+    /// Validates changes without saving them
+    ///
+    /// Off by default. While on, creating, updating and deleting events still
+    /// looks up calendars and checks the input, returning the same errors,
+    /// but nothing is written to the store. The returned [`EventItem`] shows
+    /// what would have been saved; a newly created one has an empty
+    /// `identifier`, since it was never persisted.
+    pub fn set_dry_run(&mut self, enabled: bool) {
+        self.dry_run = enabled;
+    }
+
+    /// Lets calendar titles match loosely when there's no exact match
+    ///
+    /// Off by default. While on, a title given to create or update calls
+    /// and [`get_calendar_by_title`](Self::get_calendar_by_title) that
+    /// doesn't match exactly is matched ignoring case, then as a prefix
+    /// ("work" finds "Work Projects"). An exact match always wins. If the
+    /// loose match finds several calendars,
+    /// [`EventKitError::AmbiguousCalendar`] lists them so the caller can ask
+    /// for something more specific.
+    pub fn set_fuzzy_calendar_titles(&mut self, enabled: bool) {
+        self.fuzzy_titles = enabled;
+    }
+
+    /// Sets how long blocking calls wait for EventKit to answer
+    ///
+    /// Defaults to 30 seconds. Fetches and [`request_access`](Self::request_access)
+    /// wait on a completion handler that EventKit can fail to call when its
+    /// daemon is unresponsive; after this long they give up with
+    /// [`EventKitError::Timeout`] instead of blocking forever. A permission
+    /// prompt counts against the timeout, so allow for the user reading it.
+    pub fn set_timeout(&mut self, timeout: std::time::Duration) {
+        self.timeout = timeout;
+    }
+
+    /// Calls `callback` whenever the event store reports a change
+    ///
+    /// Changes include edits made in other apps and items synced from other
+    /// devices. The notification doesn't say what changed, so re-fetch
+    /// whatever you're showing. The callback may run on any thread, and
+    /// command-line tools only receive it while a run loop is running (see
+    /// [`run_loop_for`]). Drop the returned subscription to stop listening.
+    pub fn on_change(&self, callback: impl Fn() + Send + Sync + 'static) -> ChangeSubscription {
+        ChangeSubscription::new(&self.store, callback)
+    }
+
+    /// Gets the current authorization status for calendar events
+    pub fn authorization_status() -> AuthorizationStatus {
+        let status = unsafe { EKEventStore::authorizationStatusForEntityType(EKEntityType::Event) };
+        status.into()
+    }
+
+    /// Requests full access to calendar events (blocking)
+    ///
+    /// Returns whether access was granted along with the authorization status
+    /// read back afterwards, so a refusal can be told apart: `Denied` when
+    /// the user declined, `Restricted` when a device policy (such as MDM)
+    /// blocks access.
+    pub fn request_access(&self) -> Result<AccessResult> {
+        let result = Arc::new((Mutex::new(None::<(bool, Option<String>)>), Condvar::new()));
+        let result_clone = Arc::clone(&result);
+
+        let completion = RcBlock::new(move |granted: Bool, error: *mut NSError| {
+            let error_msg = if !error.is_null() {
+                let error_ref = unsafe { &*error };
+                Some(format!("{:?}", error_ref))
+            } else {
+                None
+            };
+
+            let (lock, cvar) = &*result_clone;
+            let mut res = lock.lock().unwrap();
+            *res = Some((granted.as_bool(), error_msg));
+            cvar.notify_one();
+        });
+
+        unsafe {
+            let block_ptr = &*completion as *const _ as *mut _;
+            if supports_full_access() {
+                self.store
+                    .requestFullAccessToEventsWithCompletion(block_ptr);
+            } else {
+                #[allow(deprecated)]
+                self.store
+                    .requestAccessToEntityType_completion(EKEntityType::Event, block_ptr);
+            }
+        }
+
+        match wait_for_completion(&result, self.timeout)? {
+            (granted, None) => Ok(AccessResult {
+                granted,
+                status: Self::authorization_status(),
+            }),
+            (_, Some(error)) => Err(EventKitError::AuthorizationRequestFailed(error)),
+        }
+    }
+
+    /// Ensures we have authorization, requesting if needed
+    ///
+    /// Shows the system permission prompt when the user hasn't decided yet;
+    /// use [`check_authorization`](Self::check_authorization) to avoid that.
+    pub fn ensure_authorized(&self) -> Result<()> {
+        match self.check_authorization() {
+            Err(EventKitError::AuthorizationNotDetermined) => self.request_access()?.into_result(),
+            other => other,
+        }
+    }
+
+    /// Checks authorization without ever showing the system prompt
+    ///
+    /// Returns [`EventKitError::AuthorizationNotDetermined`] if the user
+    /// hasn't been asked yet, leaving it to the caller to decide when to
+    /// call [`request_access`](Self::request_access).
+    pub fn check_authorization(&self) -> Result<()> {
+        authorization_result(Self::authorization_status())
+    }
+
+    /// Whether access is currently granted, without ever prompting
+    pub fn is_authorized(&self) -> bool {
+        self.check_authorization().is_ok()
+    }
+
+    /// Lists all event calendars
+    pub fn list_calendars(&self) -> Result<Vec<CalendarInfo>> {
+        self.ensure_authorized()?;
+
+        let calendars = unsafe { self.store.calendarsForEntityType(EKEntityType::Event) };
+
+        let mut result = Vec::new();
+        for calendar in calendars.iter() {
+            result.push(calendar_to_info(&calendar));
+        }
+
+        Ok(result)
+    }
+
+    /// Lists only the calendars new events can be saved into
+    ///
+    /// Leaves out read-only calendars such as Birthdays and subscribed
+    /// feeds, and any calendar that can't hold events, so a picker built from
+    /// this never offers a target that would reject the save.
+    pub fn list_writable_calendars(&self) -> Result<Vec<CalendarInfo>> {
+        self.ensure_authorized()?;
+
+        let calendars = unsafe { self.store.calendarsForEntityType(EKEntityType::Event) };
+
+        Ok(calendars
+            .iter()
+            .filter(|calendar| is_writable(calendar, EntityType::Event))
+            .map(|calendar| calendar_to_info(&calendar))
+            .collect())
+    }
+
+    /// Creates a new event calendar in the given account
+    ///
+    /// `color` defaults to one EventKit picks. Subscribed and Birthdays
+    /// accounts, and any account the server won't add calendars to, return
+    /// [`EventKitError::SourceReadOnly`]; an account that doesn't exist
+    /// returns [`EventKitError::SourceNotFound`].
+    pub fn create_calendar(
+        &self,
+        title: &str,
+        source: &CalendarSource,
+        color: Option<CalendarColor>,
+    ) -> Result<CalendarInfo> {
+        self.ensure_authorized()?;
+
+        let ek_source = self.find_source(source)?;
+        let source_title = unsafe { ek_source.title() }.to_string();
+        let source_type = unsafe { ek_source.sourceType() };
+        if source_type == EKSourceType::Subscribed || source_type == EKSourceType::Birthdays {
+            return Err(EventKitError::SourceReadOnly(source_title));
+        }
+
+        let calendar = unsafe {
+            EKCalendar::calendarForEntityType_eventStore(EKEntityType::Event, &self.store)
+        };
+        unsafe {
+            calendar.setTitle(&NSString::from_str(title));
+            calendar.setSource(Some(&ek_source));
+        }
+        if let Some(color) = color {
+            let channel = |value: u8| value as f64 / 255.0;
+            let cg_color = CGColor::new_srgb(
+                channel(color.red),
+                channel(color.green),
+                channel(color.blue),
+                1.0,
+            );
+            unsafe { calendar.setCGColor(Some(&cg_color)) };
+        }
+
+        if self.dry_run {
+            let mut info = calendar_to_info(&calendar);
+            info.identifier = Default::default();
+            return Ok(info);
+        }
+
+        unsafe { self.store.saveCalendar_commit_error(&calendar, true) }.map_err(|e| {
+            let code = e.code();
+            let read_only = unsafe { EKErrorDomain }.is_some_and(|ek| *e.domain() == *ek)
+                && (code == EKErrorCode::SourceDoesNotAllowCalendarAddDelete.0
+                    || code == EKErrorCode::CalendarSourceCannotBeModified.0);
+            if read_only {
+                EventKitError::SourceReadOnly(source_title.clone())
+            } else {
+                EventKitError::SaveFailed(format!("{:?}", e))
+            }
+        })?;
+        self.calendars.invalidate();
+        Ok(calendar_to_info(&calendar))
+    }
+
+    /// Gets the default calendar for new events
+    ///
+    /// Served from this manager's calendar cache. The user can change the
+    /// default at any time in Calendar's settings; the cache picks that up
+    /// after the next store change notification or [`reset`](Self::reset).
+    pub fn default_calendar(&self) -> Result<CalendarInfo> {
+        self.ensure_authorized()?;
+
+        match self.calendars.default() {
+            Some(cal) => Ok(calendar_to_info(&cal)),
+            None => Err(EventKitError::NoDefaultCalendar),
+        }
+    }
+
+    /// Whether the calendar with this identifier is the default for new events
+    ///
+    /// Returns `false` if there is no default calendar or access hasn't been
+    /// granted.
+    pub fn is_default_calendar(&self, identifier: &CalendarId) -> bool {
+        self.check_authorization().is_ok()
+            && self.calendars.default().is_some_and(|cal| {
+                unsafe { cal.calendarIdentifier() }.to_string() == identifier.as_str()
+            })
+    }
+
+    /// Gets an event calendar by its identifier
+    ///
+    /// Served from this manager's calendar cache after the first lookup.
+    pub fn get_calendar_by_id(&self, identifier: &CalendarId) -> Result<CalendarInfo> {
+        self.ensure_authorized()?;
+        self.calendars
+            .by_id(identifier)
+            .map(|cal| calendar_to_info(&cal))
+            .ok_or_else(|| EventKitError::CalendarNotFound(identifier.to_string()))
+    }
+
+    /// Gets an event calendar by its title
+    ///
+    /// Titles aren't unique; the first matching calendar is returned.
+    pub fn get_calendar_by_title(&self, title: &str) -> Result<CalendarInfo> {
+        self.ensure_authorized()?;
+        let calendar = self.find_calendar_by_title(title)?;
+        Ok(calendar_to_info(&calendar))
+    }
+
+    /// Sets the hour (0-23) at which a "day" starts for day-based fetches
+    ///
+    /// Defaults to midnight. With a start hour of 4, the day for June 12 runs
+    /// from 04:00 on the 12th to 04:00 on the 13th, so late-night events stay
+    /// on the day they belong to. Values above 23 are clamped to 23.
+    pub fn set_day_start_hour(&mut self, hour: u32) {
+        self.day_start_hour = hour.min(23);
+    }
+
+    /// Fetches events for today in the local timezone
+    pub fn fetch_today_events(&self) -> Result<Vec<EventItem>> {
+        let now = Local::now();
+        self.fetch_day_events(self.logical_date(&now), Local)
+    }
+
+    /// Fetches the next `days` days of events, grouped by day
+    ///
+    /// Starts at the beginning of today (see
+    /// [`set_day_start_hour`](Self::set_day_start_hour)), so events earlier
+    /// today are included. Within each day all-day events are listed
+    /// separately from timed ones, both ordered by start time and then
+    /// title. Events that began before today, such as a trip already under
+    /// way, are listed under today.
+    pub fn agenda(&self, days: i64) -> Result<Agenda> {
+        let now = Local::now();
+        let today = self.logical_date(&now);
+        let (start, _) = day_bounds(today, &Local, self.day_start_hour)
+            .ok_or(EventKitError::InvalidDateRange)?;
+
+        let mut events =
+            self.fetch_events(start, start + Duration::days(days), None, None, None)?;
+        events.sort_by(|a, b| {
+            a.start_date
+                .cmp(&b.start_date)
+                .then_with(|| a.title.cmp(&b.title))
+        });
+        Ok(group_agenda(events, self.day_start_hour, Some(today)))
+    }
+
+    /// Fetches events for one day in the given timezone
+    ///
+    /// The day runs from the configured start hour (see
+    /// [`set_day_start_hour`](Self::set_day_start_hour)) on `date` to the
+    /// same hour on the following day, measured in `tz`. This lets a server
+    /// running in UTC fetch "today" for a user in another timezone.
+    pub fn fetch_day_events<Tz: TimeZone>(
+        &self,
+        date: NaiveDate,
+        tz: Tz,
+    ) -> Result<Vec<EventItem>> {
+        let (start, end) =
+            day_bounds(date, &tz, self.day_start_hour).ok_or(EventKitError::InvalidDateRange)?;
+
+        self.fetch_events(
+            start.with_timezone(&Local),
+            end.with_timezone(&Local),
+            None,
+            None,
+            None,
+        )
+    }
+
+    /// Fetches events in a date range, grouped by calendar
+    ///
+    /// Events are fetched once and split up by calendar identifier, so
+    /// calendars that share a title (say, "Work" in two accounts) stay apart.
+    /// Groups follow [`list_calendars`](Self::list_calendars) order and each
+    /// group is sorted by start date. Calendars with no events in the range
+    /// are left out unless `include_empty` is set.
+    pub fn fetch_events_grouped(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        include_empty: bool,
+    ) -> Result<Vec<(CalendarInfo, Vec<EventItem>)>> {
+        let events = self.fetch_events(start, end, None, None, None)?;
+        Ok(group_by_calendar(
+            self.list_calendars()?,
+            events,
+            include_empty,
+        ))
+    }
+
+    /// Gets the next event that starts after now
+    ///
+    /// Searches forward in windows that double in size (1 day, 2 days,
+    /// 4 days, ...) so a busy calendar only loads a day of events, while a
+    /// sparse one is still searched about four years ahead. Events already
+    /// in progress are skipped. Returns `None` when nothing is scheduled.
+    pub fn next_event(&self) -> Result<Option<EventItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let now = Local::now();
+        let horizon = now + Duration::days(NEXT_EVENT_HORIZON_DAYS);
+        let mut window_start = now;
+        let mut window_days = 1;
+
+        while window_start < horizon {
+            let window_end = (window_start + Duration::days(window_days)).min(horizon);
+            let predicate = self.events_predicate(window_start, window_end, None)?;
+            let events = unsafe { self.store.eventsMatchingPredicate(&predicate) };
+
+            let next = events
+                .iter()
+                .map(|event| {
+                    let start: Retained<NSDate> = unsafe { event.startDate() };
+                    (nsdate_to_datetime(&start), event)
+                })
+                .filter(|(start, _)| *start >= now)
+                .min_by_key(|(start, _)| *start);
+            if let Some((_, event)) = next {
+                return Ok(Some(event_to_item(&event)));
+            }
+
+            window_start = window_end;
+            window_days *= 2;
+        }
+
+        Ok(None)
+    }
+
+    /// Fetches events for the next N days, optionally capped at `limit` events
+    pub fn fetch_upcoming_events(&self, days: i64, limit: Option<usize>) -> Result<Vec<EventItem>> {
+        let now = Local::now();
+        let end = now + Duration::days(days);
+        self.fetch_events(now, end, None, None, limit)
+    }
+
+    /// Fetches events in a date range, optionally capped at `limit` events
+    ///
+    /// Events are sorted by start date and run through `filter` before the
+    /// limit is applied, so a limit of 10 returns the 10 earliest matching
+    /// events in the range.
+    ///
+    /// EventKit only searches four years per query, so longer ranges are
+    /// fetched in chunks and merged; events spanning a chunk boundary are
+    /// still returned once.
+    pub fn fetch_events(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+        filter: Option<&EventFilter>,
+        limit: Option<usize>,
+    ) -> Result<Vec<EventItem>> {
+        self.fetch_events_page(start, end, calendar_titles, filter, 0, limit)
+    }
+
+    /// Fetches one page of events in a date range
+    ///
+    /// Skips the first `skip` events (by start date) and returns at most
+    /// `limit` of the rest. Paging is applied after the fetch: EventKit still
+    /// loads every event in the range, but only the requested page is
+    /// converted and returned.
+    pub fn fetch_events_page(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+        filter: Option<&EventFilter>,
+        skip: usize,
+        limit: Option<usize>,
+    ) -> Result<Vec<EventItem>> {
+        self.fetch_events_where(
+            start,
+            end,
+            calendar_titles,
+            |event| filter.is_none_or(|f| f.matches_ek(event, start, end)),
+            skip,
+            limit,
+        )
+    }
+
+    /// Finds events in a date range whose title, notes or location contain
+    /// `query`, ignoring case
+    ///
+    /// Events are matched before being converted, so searching a long range
+    /// only pays for the hits. Results are sorted by start date.
+    pub fn search_events(
+        &self,
+        query: &str,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<EventItem>> {
+        let query = query.to_lowercase();
+        self.fetch_events_where(
+            start,
+            end,
+            calendar_titles,
+            |event| ek_text_matches(event, &query),
+            0,
+            None,
+        )
+    }
+
+    // Helper to run an EventQuery
+    fn fetch_query(&self, query: &EventQuery) -> Result<Vec<EventItem>> {
+        let (start, end) = query.range.ok_or(EventKitError::InvalidDateRange)?;
+        let titles: Option<Vec<&str>> = query
+            .calendar_titles
+            .as_ref()
+            .map(|titles| titles.iter().map(String::as_str).collect());
+        let keep = |event: &EKEvent| {
+            query.filter.matches_ek(event, start, end)
+                && query
+                    .text
+                    .as_ref()
+                    .is_none_or(|text| ek_text_matches(event, text))
+        };
+
+        // Fetched events are already in start order, so only another order
+        // needs everything converted before the limit applies
+        let sort = query.sort.filter(|sort| *sort != SortOrder::StartAsc);
+        let fetch_limit = if sort.is_some() { None } else { query.limit };
+        let mut events =
+            self.fetch_events_where(start, end, titles.as_deref(), keep, 0, fetch_limit)?;
+        if let Some(sort) = sort {
+            sort.sort_events(&mut events);
+            if let Some(limit) = query.limit {
+                events.truncate(limit);
+            }
+        }
+        Ok(events)
+    }
+
+    // Helper to fetch events in a range that pass `keep`, sorted by start
+    // date, converting only the requested page
+    fn fetch_events_where(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+        keep: impl Fn(&EKEvent) -> bool,
+        skip: usize,
+        limit: Option<usize>,
+    ) -> Result<Vec<EventItem>> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        let calendars = self.calendars_for_titles(calendar_titles)?;
+        let mut events = self.events_matching(start, end, calendars.as_deref())?;
+
+        // Filter and sort by start date, then convert only the requested page
+        events.retain(|event| keep(event));
+        events.sort_by_key(|event| unsafe { event.startDate() }.timeIntervalSince1970() as i64);
+
+        Ok(paginate(events, skip, limit)
+            .iter()
+            .map(|event| event_to_item(event))
+            .collect())
+    }
+
+    /// Fetches events in a date range changed after `since`, oldest change
+    /// first
+    ///
+    /// Like [`RemindersManager::fetch_changed_since`], but EventKit can only
+    /// search events by date, so the range to look in is needed too. Only
+    /// events that changed are converted; events without a modification date
+    /// are always included. Deleted events can't be reported.
+    pub fn fetch_changed_since(
+        &self,
+        since: DateTime<Local>,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<EventItem>> {
+        let mut events = self.fetch_events_where(
+            start,
+            end,
+            calendar_titles,
+            |event| {
+                let modified = unsafe { event.lastModifiedDate() };
+                changed_after(modified.map(|d| nsdate_to_datetime(&d)), since)
+            },
+            0,
+            None,
+        )?;
+        events.sort_by_key(|e| e.last_modified_date);
+        Ok(events)
+    }
+
+    /// Calls `f` for each event overlapping a date range as EventKit finds it
+    ///
+    /// Unlike [`fetch_events`](Self::fetch_events), events are converted one
+    /// at a time and nothing is collected, so returning
+    /// [`ControlFlow::Break`] from `f` stops the search without loading the
+    /// rest of the range. EventKit doesn't promise any order. `f` runs on
+    /// the calling thread and is never called after this method returns.
+    pub fn for_each_event(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+        f: impl FnMut(EventItem) -> ControlFlow<()>,
+    ) -> Result<()> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+        if start >= end {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let calendars = self.calendars_for_titles(calendar_titles)?;
+        let f = RefCell::new(f);
+        for (chunk_start, chunk_end) in predicate_chunks(start, end) {
+            let predicate =
+                range_predicate(&self.store, chunk_start, chunk_end, calendars.as_deref());
+            let stopped = Cell::new(false);
+            let block = RcBlock::new(|event: NonNull<EKEvent>, stop: NonNull<Bool>| {
+                let event = unsafe { event.as_ref() };
+                // Events started in an earlier chunk were already visited there
+                let event_start: Retained<NSDate> = unsafe { event.startDate() };
+                if chunk_start > start && nsdate_to_datetime(&event_start) < chunk_start {
+                    return;
+                }
+                if (f.borrow_mut())(event_to_item(event)).is_break() {
+                    stopped.set(true);
+                    unsafe { stop.write(Bool::YES) };
+                }
+            });
+            // The block borrows from this frame, which is sound because the
+            // enumeration is synchronous and EventKit never calls it after returning
+            let callback: EKEventSearchCallback =
+                unsafe { std::mem::transmute(RcBlock::as_ptr(&block)) };
+            unsafe {
+                self.store
+                    .enumerateEventsMatchingPredicate_usingBlock(&predicate, callback);
+            }
+            if stopped.get() {
+                break;
+            }
+        }
+        Ok(())
+    }
+
+    /// Fetches at most `limit` events overlapping a date range, stopping the
+    /// search as soon as that many are found
+    ///
+    /// Which events come back isn't defined when more than `limit` match,
+    /// since EventKit doesn't search in date order; the ones returned are
+    /// sorted by start date. Keep the range short (say, a day or a week) for
+    /// "the next few events" on a large calendar.
+    pub fn first_events(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+        limit: usize,
+    ) -> Result<Vec<EventItem>> {
+        let mut events = Vec::new();
+        if limit > 0 {
+            self.for_each_event(start, end, calendar_titles, |event| {
+                events.push(event);
+                if events.len() < limit {
+                    ControlFlow::Continue(())
+                } else {
+                    ControlFlow::Break(())
+                }
+            })?;
+        }
+        events.sort_by_key(|e| e.start_date);
+        Ok(events)
+    }
+
+    /// Counts events in a date range without converting them to [`EventItem`]s
+    pub fn count_events(&self, start: DateTime<Local>, end: DateTime<Local>) -> Result<usize> {
+        self.ensure_authorized()?;
+        self.refresh_if_enabled();
+
+        Ok(self.events_matching(start, end, None)?.len())
+    }
+
+    /// Finds pairs of events in a date range whose times overlap
+    ///
+    /// Two events conflict when their `[start_date, end_date)` intervals
+    /// intersect, so back-to-back events (one ends as the next starts) and
+    /// zero-length events never conflict. Only events that pass `filter` are
+    /// considered, so set `exclude_all_day` to ignore all-day events and
+    /// `exclude_canceled` to keep canceled meetings out of the results.
+    pub fn find_conflicts(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+        filter: &EventFilter,
+    ) -> Result<Vec<(EventItem, EventItem)>> {
+        let events = self.fetch_events(start, end, calendar_titles, Some(filter), None)?;
+        Ok(conflicting_pairs(&events, !filter.exclude_all_day))
+    }
+
+    /// Summarizes when a date range is busy, without event details
+    ///
+    /// Only events that block time count: those marked busy or out of
+    /// office, plus events on calendars without availability, which
+    /// Calendar treats as busy. Free, tentative and canceled events are
+    /// ignored. Overlapping and back-to-back events are merged into single
+    /// blocks, clipped to `start..end` and ordered by start.
+    pub fn free_busy(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<BusyBlock>> {
+        let filter = EventFilter {
+            exclude_canceled: true,
+            only_busy: true,
+            ..Default::default()
+        };
+        let events = self.fetch_events(start, end, calendar_titles, Some(&filter), None)?;
+        Ok(merge_busy(&events, start, end))
+    }
+
+    /// Finds free time of at least `min_duration` within a date range
+    ///
+    /// Busy time is worked out as in [`free_busy`](Self::free_busy), so
+    /// free and tentative events don't block a slot. With `working_hours`,
+    /// slots are also limited to those hours on working days. Returns
+    /// `(start, end)` pairs ordered by start.
+    ///
+    /// Returns [`EventKitError::InvalidDateRange`] if `end` isn't after
+    /// `start` or the working hours end before they start.
+    pub fn find_free_slots(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        min_duration: Duration,
+        working_hours: Option<&WorkingHours>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Vec<(DateTime<Local>, DateTime<Local>)>> {
+        if start >= end {
+            return Err(EventKitError::InvalidDateRange);
+        }
+        if working_hours.is_some_and(|hours| hours.end <= hours.start) {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let busy = self.free_busy(start, end, calendar_titles)?;
+        Ok(free_slots(&busy, start, end, min_duration, working_hours))
+    }
+
+    /// Creates a new event
+    ///
+    /// If `structured_location` is given without a plain `location`, its title
+    /// is also used as the event's location string. `time_zone` is an IANA
+    /// name such as "Europe/Paris"; it only labels the event, since `start`
+    /// and `end` are already absolute times. Without one the event uses the
+    /// calendar's default zone.
+    ///
+    /// `end` must be after `start`, or [`EventKitError::InvalidDateRange`] is
+    /// returned. All-day events may end on the day they start.
+    ///
+    /// Each of `recurrence_rules` makes the event repeat; with several, it
+    /// occurs whenever any of them does. RRULE text can be turned into rules
+    /// with [`RecurrenceRule::from_rrule`] or `str::parse`.
+    ///
+    /// `availability` sets how the event shows on free/busy time, e.g.
+    /// [`EventAvailability::Free`] for a block that shouldn't make you look
+    /// busy; `None` leaves the calendar's default. As with
+    /// [`set_event_availability`](Self::set_event_availability), one the
+    /// calendar doesn't support returns
+    /// [`EventKitError::AvailabilityUnsupported`].
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_event(
+        &self,
+        title: &str,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        calendar_title: Option<&str>,
+        all_day: bool,
+        structured_location: Option<&StructuredLocation>,
+        alarms: &[Alarm],
+        time_zone: Option<&str>,
+        recurrence_rules: &[RecurrenceRule],
+        availability: Option<EventAvailability>,
+        url: Option<&str>,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.build_event(
+            title,
+            start,
+            end,
+            notes,
+            location,
+            calendar_title,
+            all_day,
+            structured_location,
+            alarms,
+            time_zone,
+            recurrence_rules,
+            availability,
+            url,
+        )?;
+
+        // Save
+        self.finish_event(&event, EKSpan::ThisEvent, true)
+    }
+
+    /// Creates an all-day event covering `first_day` to `last_day`, inclusive
+    ///
+    /// Takes calendar dates rather than timestamps, so a day isn't shifted by
+    /// DST or time zones. Use the same date twice for a single-day event; a
+    /// `last_day` before `first_day` returns
+    /// [`EventKitError::InvalidDateRange`].
+    pub fn create_all_day_event(
+        &self,
+        title: &str,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+        notes: Option<&str>,
+        location: Option<&str>,
+        calendar_title: Option<&str>,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+        if last_day < first_day {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let (start, _) = day_bounds(first_day, &Local, 0).ok_or(EventKitError::InvalidDateRange)?;
+        let (_, end) = day_bounds(last_day, &Local, 0).ok_or(EventKitError::InvalidDateRange)?;
+        let event = self.build_event(
+            title,
+            start,
+            end,
+            notes,
+            location,
+            calendar_title,
+            true,
+            None,
+            &[],
+            None,
+            &[],
+            None,
+            None,
+        )?;
+
+        self.finish_event(&event, EKSpan::ThisEvent, true)
+    }
+
+    /// Creates a new event without committing it
+    ///
+    /// The event is staged in this manager's event store and only written
+    /// when [`commit`](Self::commit) is called; [`reset`](Self::reset)
+    /// abandons it. Its identifier may not be final until committed.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_event_deferred(
+        &self,
+        title: &str,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        calendar_title: Option<&str>,
+        all_day: bool,
+        structured_location: Option<&StructuredLocation>,
+        alarms: &[Alarm],
+        time_zone: Option<&str>,
+        recurrence_rules: &[RecurrenceRule],
+        availability: Option<EventAvailability>,
+        url: Option<&str>,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.build_event(
+            title,
+            start,
+            end,
+            notes,
+            location,
+            calendar_title,
+            all_day,
+            structured_location,
+            alarms,
+            time_zone,
+            recurrence_rules,
+            availability,
+            url,
+        )?;
+
+        self.finish_event(&event, EKSpan::ThisEvent, false)
+    }
+
+    /// Commits all staged changes to the event store
+    pub fn commit(&self) -> Result<()> {
+        unsafe { self.store.commit() }.map_err(|e| EventKitError::SaveFailed(format!("{:?}", e)))
+    }
+
+    /// Discards all uncommitted changes
+    ///
+    /// This drops everything staged since the last commit, including changes
+    /// to events that were fetched and then modified, and makes previously
+    /// fetched EventKit objects stale.
+    pub fn reset(&self) {
+        unsafe { self.store.reset() };
+        self.calendars.invalidate();
+    }
+
+    /// Updates an existing event
+    ///
+    /// `calendar_title` moves the event to another calendar, which must be
+    /// able to hold events. `all_day` converts between timed and all-day
+    /// events without touching the stored start and end.
+    ///
+    /// Returns [`EventKitError::InvalidDateRange`] if the new start and end
+    /// (or the existing ones, where not given) would not form a valid range.
+    /// Nothing is changed when any check fails.
+    ///
+    /// `availability` is checked against the calendar the event ends up in,
+    /// as in [`set_event_availability`](Self::set_event_availability).
+    /// `url` of `Some(None)` removes the event's URL.
+    ///
+    /// `identifier` names a recurring event's first occurrence, so
+    /// [`Span::ThisAndFuture`] changes the whole series.
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_event(
+        &self,
+        identifier: &EventId,
+        title: Option<&str>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        start: Option<DateTime<Local>>,
+        end: Option<DateTime<Local>>,
+        calendar_title: Option<&str>,
+        all_day: Option<bool>,
+        availability: Option<EventAvailability>,
+        url: Option<Option<&str>>,
+        span: Span,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        self.apply_event_update(
+            &event,
+            title,
+            notes,
+            location,
+            start,
+            end,
+            calendar_title,
+            all_day,
+            availability,
+            url,
+            span,
+        )
+    }
+
+    /// Updates one occurrence of a recurring event
+    ///
+    /// Works like [`update_event`](Self::update_event), starting from the
+    /// given occurrence instead of the first. With [`Span::ThisEvent`] the
+    /// occurrence becomes detached
+    /// ([`is_detached`](EventItem::is_detached)) and the rest of the series
+    /// is left alone; [`Span::ThisAndFuture`] also changes every later
+    /// occurrence. Occurrences are found as in
+    /// [`get_event_occurrence`](Self::get_event_occurrence).
+    #[allow(clippy::too_many_arguments)]
+    pub fn update_event_occurrence(
+        &self,
+        occurrence: &EventOccurrence,
+        title: Option<&str>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        start: Option<DateTime<Local>>,
+        end: Option<DateTime<Local>>,
+        calendar_title: Option<&str>,
+        all_day: Option<bool>,
+        availability: Option<EventAvailability>,
+        url: Option<Option<&str>>,
+        span: Span,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_occurrence(occurrence)?;
+        self.apply_event_update(
+            &event,
+            title,
+            notes,
+            location,
+            start,
+            end,
+            calendar_title,
+            all_day,
+            availability,
+            url,
+            span,
+        )
+    }
+
+    // Helper to apply and save the changes for update_event and
+    // update_event_occurrence
+    #[allow(clippy::too_many_arguments)]
+    fn apply_event_update(
+        &self,
+        event: &EKEvent,
+        title: Option<&str>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        start: Option<DateTime<Local>>,
+        end: Option<DateTime<Local>>,
+        calendar_title: Option<&str>,
+        all_day: Option<bool>,
+        availability: Option<EventAvailability>,
+        url: Option<Option<&str>>,
+        span: Span,
+    ) -> Result<EventItem> {
+        let url = url.map(|u| u.map(parse_url).transpose()).transpose()?;
+
+        // Check the resulting range and calendar before changing anything
+        if start.is_some() || end.is_some() || all_day.is_some() {
+            let current_start: Retained<NSDate> = unsafe { event.startDate() };
+            let current_end: Retained<NSDate> = unsafe { event.endDate() };
+            check_event_range(
+                start.unwrap_or_else(|| nsdate_to_datetime(&current_start)),
+                end.unwrap_or_else(|| nsdate_to_datetime(&current_end)),
+                all_day.unwrap_or_else(|| unsafe { event.isAllDay() }),
+            )?;
+        }
+
+        let calendar = calendar_title
+            .map(|cal_title| self.find_calendar_by_title(cal_title))
+            .transpose()?;
+        if let Some(ref calendar) = calendar {
+            ensure_calendar_allows(calendar, EntityType::Event)?;
+        }
+        let ek_availability = availability
+            .map(|availability| match calendar {
+                Some(ref calendar) => check_calendar_availability(Some(calendar), availability),
+                None => check_calendar_availability(
+                    unsafe { event.calendar() }.as_deref(),
+                    availability,
+                ),
+            })
+            .transpose()?;
+
+        if let Some(t) = title {
+            let ns_title = NSString::from_str(t);
+            unsafe { event.setTitle(Some(&ns_title)) };
+        }
+
+        if let Some(n) = notes {
+            let ns_notes = NSString::from_str(n);
+            unsafe { event.setNotes(Some(&ns_notes)) };
+        }
+
+        if let Some(l) = location {
+            let ns_location = NSString::from_str(l);
+            unsafe { event.setLocation(Some(&ns_location)) };
+        }
+
+        if let Some(s) = start {
+            let start_date = datetime_to_nsdate(s);
+            unsafe { event.setStartDate(Some(&start_date)) };
+        }
+
+        if let Some(e) = end {
+            let end_date = datetime_to_nsdate(e);
+            unsafe { event.setEndDate(Some(&end_date)) };
+        }
+
+        if let Some(a) = all_day {
+            unsafe { event.setAllDay(a) };
+        }
+
+        if let Some(ek_availability) = ek_availability {
+            unsafe { event.setAvailability(ek_availability) };
+        }
+
+        if let Some(u) = url {
+            unsafe { event.setURL(u.as_deref()) };
+        }
+
+        if let Some(calendar) = calendar {
+            unsafe { event.setCalendar(Some(&calendar)) };
+        }
+
+        self.finish_event(event, span.into(), true)
+    }
+
+    /// Replaces an event's recurrence rules
+    ///
+    /// An empty `rules` stops the event repeating. The change applies to the
+    /// whole series, from its first occurrence on.
+    pub fn set_event_recurrence(
+        &self,
+        identifier: &EventId,
+        rules: &[RecurrenceRule],
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        if let Some(existing) = unsafe { event.recurrenceRules() } {
+            for rule in existing.iter() {
+                unsafe { event.removeRecurrenceRule(&rule) };
+            }
+        }
+        for rule in rules {
+            unsafe { event.addRecurrenceRule(&recurrence_to_ek(rule)) };
+        }
+
+        self.finish_event(&event, EKSpan::FutureEvents, true)
+    }
+
+    /// Replaces all of an event's alarms
+    ///
+    /// Pass an empty slice to remove them. On a recurring event, the whole
+    /// series changes. A proximity alarm with out-of-range coordinates
+    /// returns [`EventKitError::InvalidLocation`] and the event is unchanged.
+    pub fn set_event_alarms(&self, identifier: &EventId, alarms: &[Alarm]) -> Result<EventItem> {
+        self.ensure_authorized()?;
+        check_alarm_locations(alarms)?;
+
+        let event = self.find_event_by_id(identifier)?;
+        let alarms: Vec<Retained<EKAlarm>> = alarms.iter().map(alarm_to_ek).collect();
+        unsafe { event.setAlarms(Some(&NSArray::from_retained_slice(&alarms))) };
+
+        self.finish_event(&event, EKSpan::FutureEvents, true)
+    }
+
+    /// Adds one alarm to an event, keeping the ones it already has
+    ///
+    /// `Alarm::Relative(Duration::minutes(-15))` fires 15 minutes before the
+    /// start; [`Alarm::on_arrival`] and [`Alarm::on_departure`] fire on
+    /// reaching or leaving a place, such as the event's own
+    /// [`structured_location`](EventItem::structured_location). Otherwise
+    /// behaves like [`set_event_alarms`](Self::set_event_alarms).
+    pub fn add_alarm(&self, identifier: &EventId, alarm: Alarm) -> Result<EventItem> {
+        self.ensure_authorized()?;
+        check_alarm_locations(std::slice::from_ref(&alarm))?;
+
+        let event = self.find_event_by_id(identifier)?;
+        unsafe { event.addAlarm(&alarm_to_ek(&alarm)) };
+
+        self.finish_event(&event, EKSpan::FutureEvents, true)
+    }
+
+    /// Removes every alarm on an event equal to `alarm`
+    ///
+    /// Returns [`EventKitError::ItemNotFound`] if the event has no such
+    /// alarm. On a recurring event, the whole series changes.
+    pub fn remove_alarm(&self, identifier: &EventId, alarm: &Alarm) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        let matching: Vec<Retained<EKAlarm>> = unsafe { event.alarms() }
+            .map(|alarms| {
+                alarms
+                    .iter()
+                    .filter(|a| alarm_from_ek(a) == *alarm)
+                    .collect()
+            })
+            .unwrap_or_default();
+        if matching.is_empty() {
+            return Err(EventKitError::ItemNotFound(format!(
+                "alarm {:?} on {}",
+                alarm, identifier
+            )));
+        }
+        for ek_alarm in &matching {
+            unsafe { event.removeAlarm(ek_alarm) };
+        }
+
+        self.finish_event(&event, EKSpan::FutureEvents, true)
+    }
+
+    /// Creates an event, or updates the one created earlier with the same key
+    ///
+    /// Meant for importers that run repeatedly: pass the source system's
+    /// stable id as `external_key` and re-running the import updates events
+    /// in place instead of creating duplicates. Returns the event and `true`
+    /// if it was created, `false` if an existing one was updated. On update,
+    /// `notes` and `location` left as `None` keep their current values.
+    ///
+    /// EventKit doesn't let apps set an event's external identifier, so the
+    /// key is stored in the event's URL as `eventkit-rs-key:<key>`. That has
+    /// limits:
+    ///
+    /// - the event can't also carry a URL of its own;
+    /// - editing the URL in Calendar breaks the link, and the next run creates
+    ///   a new event;
+    /// - the existing event is only looked for within a year either side of
+    ///   `start`, so one moved further than that is created again;
+    /// - the lookup scans every event in that window, so it's slower than a
+    ///   plain create.
+    #[allow(clippy::too_many_arguments)]
+    pub fn upsert_event(
+        &self,
+        external_key: &str,
+        title: &str,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        calendar_title: Option<&str>,
+        all_day: bool,
+    ) -> Result<(EventItem, bool)> {
+        self.ensure_authorized()?;
+        check_event_range(start, end, all_day)?;
+
+        let marker = upsert_marker(external_key);
+        let window = Duration::days(UPSERT_SEARCH_DAYS);
+        let predicate = self.events_predicate(start - window, start + window, None)?;
+        let events = unsafe { self.store.eventsMatchingPredicate(&predicate) };
+        let existing = events.iter().find(|event| {
+            unsafe { event.URL() }
+                .and_then(|url| url.absoluteString())
+                .is_some_and(|url| url.to_string() == marker)
+        });
+
+        if let Some(event) = existing {
+            let identifier = event_to_item(&event).identifier;
+            let item = self.update_event(
+                &identifier,
+                Some(title),
+                notes,
+                location,
+                Some(start),
+                Some(end),
+                calendar_title,
+                Some(all_day),
+                None,
+                None,
+                Span::ThisEvent,
+            )?;
+            return Ok((item, false));
+        }
+
+        let event = self.build_event(
+            title,
+            start,
+            end,
+            notes,
+            location,
+            calendar_title,
+            all_day,
+            None,
+            &[],
+            None,
+            &[],
+            None,
+            None,
+        )?;
+        let url = parse_url(&marker)?;
+        unsafe { event.setURL(Some(&url)) };
+
+        Ok((self.finish_event(&event, EKSpan::ThisEvent, true)?, true))
+    }
+
+    /// Copies an event to a new start time
+    ///
+    /// The copy keeps the title, notes, location, URL, time zone, alarms and
+    /// all-day flag, and
+    /// its end moves with the start so the duration is unchanged; absolute
+    /// alarms shift by the same amount. It goes into `calendar_title` if
+    /// given, otherwise the source's calendar, and gets its own identifier.
+    ///
+    /// For a recurring event only the one occurrence is copied: the new event
+    /// doesn't repeat. Attendees aren't copied, since EventKit can't set them.
+    pub fn duplicate_event(
+        &self,
+        identifier: &EventId,
+        new_start: DateTime<Local>,
+        calendar_title: Option<&str>,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let source = self.find_event_by_id(identifier)?;
+        let item = event_to_item(&source);
+
+        let shift = new_start - item.start_date;
+        let alarms: Vec<Alarm> = item
+            .alarms
+            .iter()
+            .map(|alarm| match alarm {
+                Alarm::Absolute(at) => Alarm::Absolute(*at + shift),
+                other => other.clone(),
+            })
+            .collect();
+
+        let event = self.build_event(
+            &item.title,
+            new_start,
+            item.end_date + shift,
+            item.notes.as_deref(),
+            item.location.as_deref(),
+            calendar_title.or(item.calendar_title.as_deref()),
+            item.all_day,
+            item.structured_location.as_ref(),
+            &alarms,
+            item.time_zone.as_deref(),
+            &[],
+            None,
+            item.url.as_deref(),
+        )?;
+
+        // Calendar titles aren't unique, so keep the exact source calendar
+        if calendar_title.is_none()
+            && let Some(calendar) = unsafe { source.calendar() }
+        {
+            unsafe { event.setCalendar(Some(&calendar)) };
+        }
+
+        self.finish_event(&event, EKSpan::ThisEvent, true)
+    }
+
+    /// Moves an event's start and end by `by` (back, for a negative duration)
+    ///
+    /// The duration is unchanged. Relative alarms follow the start on their
+    /// own; absolute alarms move by the same amount. For a recurring event
+    /// only the first occurrence moves.
+    pub fn shift_event(&self, identifier: &EventId, by: Duration) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        let start: Retained<NSDate> = unsafe { event.startDate() };
+        let end: Retained<NSDate> = unsafe { event.endDate() };
+        unsafe {
+            event.setStartDate(Some(&datetime_to_nsdate(nsdate_to_datetime(&start) + by)));
+            event.setEndDate(Some(&datetime_to_nsdate(nsdate_to_datetime(&end) + by)));
+        }
+        shift_absolute_alarms(&event, by);
+
+        self.finish_event(&event, EKSpan::ThisEvent, true)
+    }
+
+    /// Moves only an event's end by `by`, making it longer (or shorter, for a
+    /// negative duration)
+    ///
+    /// Returns [`EventKitError::InvalidDateRange`] if the event would end
+    /// before it starts, leaving it unchanged. For a recurring event only the
+    /// first occurrence changes.
+    pub fn extend_event(&self, identifier: &EventId, by: Duration) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        let start: Retained<NSDate> = unsafe { event.startDate() };
+        let end: Retained<NSDate> = unsafe { event.endDate() };
+        let new_end = nsdate_to_datetime(&end) + by;
+        check_event_range(nsdate_to_datetime(&start), new_end, unsafe {
+            event.isAllDay()
+        })?;
+        unsafe { event.setEndDate(Some(&datetime_to_nsdate(new_end))) };
+
+        self.finish_event(&event, EKSpan::ThisEvent, true)
+    }
+
+    /// Changes how an event shows on your free/busy time
+    ///
+    /// This is the closest EventKit gets to marking an event tentative
+    /// without deleting it: the event's [`EventStatus`] is read-only, so a
+    /// meeting can't be marked canceled here. Calendars support different
+    /// sets of availabilities (many CalDAV servers only know busy and free);
+    /// asking for one the event's calendar doesn't support, or for
+    /// [`EventAvailability::NotSupported`], returns
+    /// [`EventKitError::AvailabilityUnsupported`] and leaves the event as is.
+    pub fn set_event_availability(
+        &self,
+        identifier: &EventId,
+        availability: EventAvailability,
+    ) -> Result<EventItem> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        let ek_availability =
+            check_calendar_availability(unsafe { event.calendar() }.as_deref(), availability)?;
+        unsafe { event.setAvailability(ek_availability) };
+
+        self.finish_event(&event, EKSpan::ThisEvent, true)
+    }
+
+    /// Deletes an event
+    ///
+    /// With [`Span::ThisAndFuture`], deleting a recurring event removes the
+    /// whole series.
+    pub fn delete_event(&self, identifier: &EventId, span: Span) -> Result<()> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_by_id(identifier)?;
+        self.remove_event(&event, span, true)
+    }
+
+    /// Deletes one occurrence of a recurring event, leaving the rest of the
+    /// series in place
+    pub fn delete_event_occurrence(&self, occurrence: &EventOccurrence) -> Result<()> {
+        self.ensure_authorized()?;
+
+        let event = self.find_event_occurrence(occurrence)?;
+        self.remove_event(&event, Span::ThisEvent, true)
+    }
+
+    /// Deletes several events with a single commit
+    ///
+    /// Each identifier is attempted on its own: one that can't be found or
+    /// removed is reported in the returned list without stopping the rest.
+    /// An error is only returned for the call as a whole when access is
+    /// denied or the final commit fails; the removals then stay staged and
+    /// can be retried with [`commit`](Self::commit) or dropped with
+    /// [`reset`](Self::reset). `span` applies to every event, as in
+    /// [`delete_event`](Self::delete_event).
+    pub fn delete_events(
+        &self,
+        identifiers: &[EventId],
+        span: Span,
+    ) -> Result<Vec<(EventId, Result<()>)>> {
+        self.ensure_authorized()?;
+
+        let results = identifiers
+            .iter()
+            .map(|id| {
+                let outcome = self
+                    .find_event_by_id(id)
+                    .and_then(|event| self.remove_event(&event, span, false));
+                (id.clone(), outcome)
+            })
+            .collect();
+
+        if !self.dry_run {
+            unsafe { self.store.commit() }
+                .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))?;
+        }
+
+        Ok(results)
+    }
+
+    /// Gets an event by its identifier
+    pub fn get_event(&self, identifier: &EventId) -> Result<EventItem> {
+        self.ensure_authorized()?;
+        let event = self.find_event_by_id(identifier)?;
+        Ok(event_to_item(&event))
+    }
+
+    /// Gets one occurrence of a recurring event
+    ///
+    /// Occurrences are searched for within a day either side of their
+    /// original date, so one moved further away than that isn't found.
+    pub fn get_event_occurrence(&self, occurrence: &EventOccurrence) -> Result<EventItem> {
+        self.ensure_authorized()?;
+        let event = self.find_event_occurrence(occurrence)?;
+        Ok(event_to_item(&event))
+    }
+
+    /// Fetches every occurrence of an event that overlaps a date range
+    ///
+    /// Each occurrence carries its own start, end and
+    /// [`occurrence_date`](EventItem::occurrence_date), so detached
+    /// occurrences (ones edited on their own) show their edited times. Sorted
+    /// by start date. An event that doesn't repeat comes back once if it
+    /// overlaps the range.
+    pub fn fetch_occurrences(
+        &self,
+        identifier: &EventId,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+    ) -> Result<Vec<EventItem>> {
+        self.ensure_authorized()?;
+        if start >= end {
+            return Err(EventKitError::InvalidDateRange);
+        }
+        self.refresh_if_enabled();
+
+        // Only the series' own calendar can hold its occurrences
+        let event = self.find_event_by_id(identifier)?;
+        let calendars = unsafe { event.calendar() }.map(|c| NSArray::from_retained_slice(&[c]));
+        let events = self.events_matching(start, end, calendars.as_deref())?;
+
+        let mut occurrences: Vec<EventItem> = events
+            .iter()
+            .filter(|event| {
+                unsafe { event.eventIdentifier() }
+                    .is_some_and(|id| id.to_string() == identifier.as_str())
+            })
+            .map(|event| event_to_item(event))
+            .collect();
+        occurrences.sort_by_key(|e| e.start_date);
+        occurrences.dedup_by_key(|e| (e.start_date, e.occurrence_date));
+        Ok(occurrences)
+    }
+
+    /// Gets all events with the given external identifier
+    ///
+    /// External identifiers are shared by every occurrence of a recurring
+    /// event and by duplicated invitations, so every match is returned
+    /// (possibly none). Unlike [`EventItem::identifier`], the external
+    /// identifier survives full syncs and is the same on every device, so
+    /// it's the one to store for references that outlive this machine.
+    #[doc(alias = "find_events_by_external_id")]
+    pub fn get_event_by_external_id(&self, external_id: &str) -> Result<Vec<EventItem>> {
+        self.ensure_authorized()?;
+        let ns_id = NSString::from_str(external_id);
+        let items = unsafe { self.store.calendarItemsWithExternalIdentifier(&ns_id) };
+
+        Ok(items
+            .iter()
+            .filter_map(|item| item.downcast_ref::<EKEvent>().map(event_to_item))
+            .collect())
+    }
+
+    // Helper to find which day `now` belongs to once the day start hour is applied
+    fn logical_date<Tz: TimeZone>(&self, now: &DateTime<Tz>) -> NaiveDate {
+        let shifted = now.naive_local() - Duration::hours(self.day_start_hour as i64);
+        shifted.date()
+    }
+
+    // Helper to build a predicate for events in a date range and calendars.
+    // Only for ranges known to fit in one predicate; see `events_matching`.
+    fn events_predicate(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Retained<NSPredicate>> {
+        if start >= end {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let calendars = self.calendars_for_titles(calendar_titles)?;
+        Ok(range_predicate(
+            &self.store,
+            start,
+            end,
+            calendars.as_deref(),
+        ))
+    }
+
+    // Helper to look up the calendars to fetch events from; `None` means all
+    fn calendars_for_titles(
+        &self,
+        calendar_titles: Option<&[&str]>,
+    ) -> Result<Option<Retained<NSArray<EKCalendar>>>> {
+        let Some(titles) = calendar_titles else {
+            return Ok(None);
+        };
+
+        let matching = self.calendars.matching_titles(titles);
+        if matching.is_empty() {
+            return Err(EventKitError::CalendarNotFound(titles.join(", ")));
+        }
+        Ok(Some(NSArray::from_retained_slice(&matching)))
+    }
+
+    // Helper to fetch the events overlapping a date range of any length,
+    // running one predicate per chunk of at most `PREDICATE_MAX_DAYS`
+    fn events_matching(
+        &self,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        calendars: Option<&NSArray<EKCalendar>>,
+    ) -> Result<Vec<Retained<EKEvent>>> {
+        if start >= end {
+            return Err(EventKitError::InvalidDateRange);
+        }
+
+        let mut events = Vec::new();
+        for (chunk_start, chunk_end) in predicate_chunks(start, end) {
+            let predicate = range_predicate(&self.store, chunk_start, chunk_end, calendars);
+            events.extend(unsafe { self.store.eventsMatchingPredicate(&predicate) }.iter());
+        }
+
+        // Events crossing a chunk boundary come back from both chunks, and
+        // occurrences of a recurring event share an identifier, so an event
+        // only counts as repeated if its start matches too
+        dedup_by_key(&mut events, |event| unsafe {
+            (
+                event.eventIdentifier().map(|id| id.to_string()),
+                event.startDate().timeIntervalSince1970().to_bits(),
+            )
+        });
+        Ok(events)
+    }
+
+    // Helper to build (but not save) a new event
+    #[allow(clippy::too_many_arguments)]
+    fn build_event(
+        &self,
+        title: &str,
+        start: DateTime<Local>,
+        end: DateTime<Local>,
+        notes: Option<&str>,
+        location: Option<&str>,
+        calendar_title: Option<&str>,
+        all_day: bool,
+        structured_location: Option<&StructuredLocation>,
+        alarms: &[Alarm],
+        time_zone: Option<&str>,
+        recurrence_rules: &[RecurrenceRule],
+        availability: Option<EventAvailability>,
+        url: Option<&str>,
+    ) -> Result<Retained<EKEvent>> {
+        check_event_range(start, end, all_day)?;
+        check_alarm_locations(alarms)?;
+        let url = url.map(parse_url).transpose()?;
+        let time_zone = time_zone.map(parse_time_zone).transpose()?;
+        let event = unsafe { EKEvent::eventWithEventStore(&self.store) };
+
+        // Set title
+        let ns_title = NSString::from_str(title);
+        unsafe { event.setTitle(Some(&ns_title)) };
+
+        // Set dates
+        let start_date = datetime_to_nsdate(start);
+        let end_date = datetime_to_nsdate(end);
+        unsafe {
+            event.setStartDate(Some(&start_date));
+            event.setEndDate(Some(&end_date));
+            event.setAllDay(all_day);
+        }
+
+        // Pin the event to a time zone if one was given
+        if let Some(zone) = time_zone {
+            unsafe { event.setTimeZone(Some(&zone)) };
+        }
+
+        // Set notes if provided
+        if let Some(notes_text) = notes {
+            let ns_notes = NSString::from_str(notes_text);
+            unsafe { event.setNotes(Some(&ns_notes)) };
+        }
+
+        // Set structured location if provided
+        if let Some(loc) = structured_location {
+            let ek_location = structured_location_to_ek(loc);
+            unsafe { event.setStructuredLocation(Some(&ek_location)) };
+        }
+
+        // Set location if provided, keeping it in sync with the structured one
+        if let Some(loc) = location.or(structured_location.map(|l| l.title.as_str())) {
+            let ns_location = NSString::from_str(loc);
+            unsafe { event.setLocation(Some(&ns_location)) };
+        }
+
+        // Link the URL if provided
+        if let Some(u) = url {
+            unsafe { event.setURL(Some(&u)) };
+        }
+
+        // Attach alarms
+        for alarm in alarms {
+            unsafe { event.addAlarm(&alarm_to_ek(alarm)) };
+        }
+
+        // Attach recurrence rules
+        for rule in recurrence_rules {
+            unsafe { event.addRecurrenceRule(&recurrence_to_ek(rule)) };
+        }
+
+        // Set calendar
+        let calendar = if let Some(cal_title) = calendar_title {
+            self.find_calendar_by_title(cal_title)?
+        } else {
+            self.calendars
+                .default()
+                .ok_or(EventKitError::NoDefaultCalendar)?
+        };
+        ensure_calendar_allows(&calendar, EntityType::Event)?;
+        if let Some(availability) = availability {
+            let ek_availability = check_calendar_availability(Some(&calendar), availability)?;
+            unsafe { event.setAvailability(ek_availability) };
+        }
+        unsafe { event.setCalendar(Some(&calendar)) };
+
+        Ok(event)
+    }
+
+    // Helper to save an event and convert it; in dry-run mode the event is
+    // converted and its unsaved changes are rolled back instead
+    fn finish_event(&self, event: &EKEvent, span: EKSpan, commit: bool) -> Result<EventItem> {
+        if self.dry_run {
+            let mut item = event_to_item(event);
+            if unsafe { event.isNew() } {
+                item.identifier = Default::default();
+                item.external_identifier = None;
+            }
+            unsafe { event.rollback() };
+            return Ok(item);
+        }
+
+        self.save_event(event, span, commit)?;
+        Ok(event_to_item(event))
+    }
+
+    // Helper to save an event, retrying transient failures per the retry policy
+    fn save_event(&self, event: &EKEvent, span: EKSpan, commit: bool) -> Result<()> {
+        with_retry(&self.retry, is_transient_error, || unsafe {
+            self.store.saveEvent_span_commit_error(event, span, commit)
+        })
+        .map_err(|e| EventKitError::SaveFailed(format!("{:?}", e)))
+    }
+
+    // Helper to remove an event, or do nothing in dry-run mode
+    fn remove_event(&self, event: &EKEvent, span: Span, commit: bool) -> Result<()> {
+        if self.dry_run {
+            return Ok(());
+        }
+        unsafe {
+            self.store
+                .removeEvent_span_commit_error(event, span.into(), commit)
+        }
+        .map_err(|e| EventKitError::DeleteFailed(format!("{:?}", e)))
+    }
+
+    // Helper to refresh sources before a fetch when auto-refresh is on
+    fn refresh_if_enabled(&self) {
+        if self.auto_refresh {
+            self.refresh();
+        }
+    }
+
+    // Helper to find the account a new calendar goes in
+    fn find_source(&self, source: &CalendarSource) -> Result<Retained<EKSource>> {
+        if *source == CalendarSource::Default {
+            return unsafe { self.store.defaultCalendarForNewEvents() }
+                .and_then(|calendar| unsafe { calendar.source() })
+                .ok_or(EventKitError::NoDefaultCalendar);
+        }
+
+        unsafe { self.store.sources() }
+            .iter()
+            .find(|ek_source| unsafe {
+                source_matches(
+                    source,
+                    &ek_source.title().to_string(),
+                    ek_source.sourceType(),
+                )
+            })
+            .ok_or_else(|| EventKitError::SourceNotFound(source.to_string()))
+    }
+
+    // Helper to find a calendar by title
+    fn find_calendar_by_title(&self, title: &str) -> Result<Retained<EKCalendar>> {
+        self.calendars.resolve_title(title, self.fuzzy_titles)
+    }
+
+    // Helper to find one occurrence of an event by identifier and original date
+    fn find_event_occurrence(&self, occurrence: &EventOccurrence) -> Result<Retained<EKEvent>> {
+        let start = occurrence.occurrence_date - Duration::days(1);
+        let end = occurrence.occurrence_date + Duration::days(1);
+        let predicate = self.events_predicate(start, end, None)?;
+        let events = unsafe { self.store.eventsMatchingPredicate(&predicate) };
+
+        let wanted = occurrence.occurrence_date.timestamp();
+        events
+            .iter()
+            .find(|event| {
+                unsafe { event.eventIdentifier() }
+                    .is_some_and(|id| id.to_string() == occurrence.identifier.as_str())
+                    && unsafe { event.occurrenceDate() }
+                        .is_some_and(|date| date.timeIntervalSince1970() as i64 == wanted)
+            })
+            .ok_or_else(|| {
+                EventKitError::ItemNotFound(format!(
+                    "{} (occurrence on {})",
+                    occurrence.identifier,
+                    occurrence.occurrence_date.format("%Y-%m-%d %H:%M")
+                ))
+            })
+    }
+
+    // Helper to find an event by identifier
+    fn find_event_by_id(&self, identifier: &str) -> Result<Retained<EKEvent>> {
+        let ns_id = NSString::from_str(identifier);
+        let event = unsafe { self.store.eventWithIdentifier(&ns_id) };
+
+        match event {
+            Some(e) => Ok(e),
+            None => Err(EventKitError::ItemNotFound(identifier.to_string())),
+        }
+    }
+}
+
+impl Default for EventsManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Helper function to convert EKEvent to EventItem
+fn event_to_item(event: &EKEvent) -> EventItem {
+    let identifier = unsafe { event.eventIdentifier() }
+        .map(|s| EventId::new(s.to_string()))
+        .unwrap_or_default();
+    let external_identifier =
+        unsafe { event.calendarItemExternalIdentifier() }.map(|s| s.to_string());
+    let title = item_title(event);
+    let notes = unsafe { event.notes() }.map(|n| n.to_string());
+    let location = unsafe { event.location() }.map(|l| l.to_string());
+    let url = unsafe { event.URL() }
+        .and_then(|u| u.absoluteString())
+        .map(|u| u.to_string());
+    let all_day = unsafe { event.isAllDay() };
+    let time_zone = unsafe { event.timeZone() }.map(|tz| tz.name().to_string());
+    let has_recurrence = unsafe { event.hasRecurrenceRules() };
+    let recurrence_rules = if has_recurrence {
+        unsafe { event.recurrenceRules() }
+            .map(|rules| {
+                rules
+                    .iter()
+                    .filter_map(|r| recurrence_from_ek(&r))
+                    .collect()
+            })
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let is_detached = unsafe { event.isDetached() };
+    let occurrence_date = if has_recurrence || is_detached {
+        unsafe { event.occurrenceDate() }.map(|d| nsdate_to_datetime(&d))
+    } else {
+        None
+    };
+    let calendar = unsafe { event.calendar() };
+    let calendar_title = calendar.as_ref().map(|c| unsafe { c.title() }.to_string());
+    let calendar_identifier = calendar
+        .as_ref()
+        .map(|c| CalendarId::new(unsafe { c.calendarIdentifier() }.to_string()));
+
+    let structured_location =
+        unsafe { event.structuredLocation() }.and_then(|l| structured_location_from_ek(&l));
+    // The has* flags are cheap, so skip materializing empty alarm and attendee lists
+    let has_notes = unsafe { event.hasNotes() };
+    let has_alarms = unsafe { event.hasAlarms() };
+    let has_attendees = unsafe { event.hasAttendees() };
+    let alarms = if has_alarms {
+        alarms_from_item(event)
+    } else {
+        Vec::new()
+    };
+    let attendees = if has_attendees {
+        unsafe { event.attendees() }
+            .map(|list| list.iter().map(|p| participant_from_ek(&p)).collect())
+            .unwrap_or_default()
+    } else {
+        Vec::new()
+    };
+    let my_participation_status = attendees
+        .iter()
+        .find(|p| p.is_current_user)
+        .map(|p| p.status);
+    let organizer = unsafe { event.organizer() }.map(|p| participant_from_ek(&p));
+    let availability = unsafe { event.availability() }.into();
+    let status = unsafe { event.status() }.into();
+    let creation_date = unsafe { event.creationDate() }.map(|d| nsdate_to_datetime(&d));
+    let last_modified_date = unsafe { event.lastModifiedDate() }.map(|d| nsdate_to_datetime(&d));
+
+    let start_ns: Retained<NSDate> = unsafe { event.startDate() };
+    let end_ns: Retained<NSDate> = unsafe { event.endDate() };
+
+    let start_date = nsdate_to_datetime(&start_ns);
+    let end_date = nsdate_to_datetime(&end_ns);
+
+    EventItem {
+        identifier,
+        external_identifier,
+        title,
+        notes,
+        has_notes,
+        location,
+        url,
+        start_date,
+        end_date,
+        all_day,
+        occurrence_date,
+        has_recurrence,
+        recurrence_rules,
+        is_detached,
+        time_zone,
+        calendar_title,
+        calendar_identifier,
+        structured_location,
+        alarms,
+        has_alarms,
+        attendees,
+        has_attendees,
+        my_participation_status,
+        organizer,
+        availability,
+        status,
+        creation_date,
+        last_modified_date,
+    }
+}
+
+// Helper to check that an event ends after it starts; a single-day all-day
+// event may have its end equal to its start
+fn check_event_range(start: DateTime<Local>, end: DateTime<Local>, all_day: bool) -> Result<()> {
+    if end > start || (all_day && end == start) {
+        Ok(())
+    } else {
+        Err(EventKitError::InvalidDateRange)
+    }
+}
+
+// Helper to convert EKParticipant to Participant
+fn participant_from_ek(participant: &EKParticipant) -> Participant {
+    let url = unsafe { participant.URL() }
+        .absoluteString()
+        .map(|u| u.to_string());
+
+    Participant {
+        name: unsafe { participant.name() }.map(|n| n.to_string()),
+        email: url.as_deref().and_then(email_from_url),
+        url,
+        role: unsafe { participant.participantRole() }.into(),
+        status: unsafe { participant.participantStatus() }.into(),
+        is_current_user: unsafe { participant.isCurrentUser() },
+    }
+}
+
+// Helper to check whether the current user declined an event, without
+// converting its attendees
+fn declined_by_current_user(event: &EKEvent) -> bool {
+    if !unsafe { event.hasAttendees() } {
+        return false;
+    }
+    unsafe { event.attendees() }.is_some_and(|attendees| {
+        attendees.iter().any(|p| unsafe {
+            p.isCurrentUser() && p.participantStatus() == EKParticipantStatus::Declined
+        })
+    })
+}
+
+// Helper to pull the address out of a `mailto:` URL
+fn email_from_url(url: &str) -> Option<String> {
+    let scheme = url.get(..7)?;
+    if !scheme.eq_ignore_ascii_case("mailto:") {
+        return None;
+    }
+    let address = url[7..].split('?').next().unwrap_or_default();
+    (!address.is_empty()).then(|| address.to_string())
+}
+
+// Helper to convert Alarm to EKAlarm
+fn alarm_to_ek(alarm: &Alarm) -> Retained<EKAlarm> {
+    match alarm {
+        Alarm::Relative(offset) => unsafe {
+            EKAlarm::alarmWithRelativeOffset(offset.num_seconds() as f64)
+        },
+        Alarm::Absolute(date) => unsafe {
+            EKAlarm::alarmWithAbsoluteDate(&datetime_to_nsdate(*date))
+        },
+        Alarm::Proximity { trigger, location } => {
+            let ek_alarm = unsafe { EKAlarm::alarmWithRelativeOffset(0.0) };
+            let proximity = match trigger {
+                Proximity::Enter => EKAlarmProximity::Enter,
+                Proximity::Leave => EKAlarmProximity::Leave,
+            };
+            unsafe {
+                ek_alarm.setStructuredLocation(Some(&structured_location_to_ek(location)));
+                ek_alarm.setProximity(proximity);
+            }
+            ek_alarm
+        }
+    }
+}
+
+// Helper to move an item's absolute alarms by `by`; relative alarms are left alone
+fn shift_absolute_alarms(item: &EKCalendarItem, by: Duration) {
+    let Some(alarms) = (unsafe { item.alarms() }) else {
+        return;
+    };
+    for alarm in alarms.iter() {
+        if let Some(date) = unsafe { alarm.absoluteDate() } {
+            let shifted = datetime_to_nsdate(nsdate_to_datetime(&date) + by);
+            unsafe { alarm.setAbsoluteDate(Some(&shifted)) };
+        }
+    }
+}
+
+// Helper to convert EKAlarm to Alarm
+fn alarm_from_ek(alarm: &EKAlarm) -> Alarm {
+    let proximity = unsafe { alarm.proximity() };
+    let trigger = if proximity == EKAlarmProximity::Enter {
+        Some(Proximity::Enter)
+    } else if proximity == EKAlarmProximity::Leave {
+        Some(Proximity::Leave)
+    } else {
+        None
+    };
+    let location =
+        unsafe { alarm.structuredLocation() }.and_then(|l| structured_location_from_ek(&l));
+
+    if let (Some(trigger), Some(location)) = (trigger, location) {
+        return Alarm::Proximity { trigger, location };
+    }
+
+    match unsafe { alarm.absoluteDate() } {
+        Some(date) => Alarm::Absolute(nsdate_to_datetime(&date)),
+        None => Alarm::Relative(Duration::seconds(unsafe { alarm.relativeOffset() } as i64)),
+    }
+}
+
+// Helper to convert an EKRecurrenceRule, skipping frequencies this crate doesn't know
+fn recurrence_from_ek(rule: &EKRecurrenceRule) -> Option<RecurrenceRule> {
+    let frequency = match unsafe { rule.frequency() } {
+        EKRecurrenceFrequency::Daily => RecurrenceFrequency::Daily,
+        EKRecurrenceFrequency::Weekly => RecurrenceFrequency::Weekly,
+        EKRecurrenceFrequency::Monthly => RecurrenceFrequency::Monthly,
+        EKRecurrenceFrequency::Yearly => RecurrenceFrequency::Yearly,
+        _ => return None,
+    };
+    let end = unsafe { rule.recurrenceEnd() }.map(|end| match unsafe { end.endDate() } {
+        Some(date) => RecurrenceEnd::Until(nsdate_to_datetime(&date)),
+        None => RecurrenceEnd::Count(unsafe { end.occurrenceCount() }),
+    });
+    let days_of_week = unsafe { rule.daysOfTheWeek() }
+        .map(|days| {
+            days.iter()
+                .filter_map(|day| {
+                    Some(RecurrenceDay {
+                        weekday: weekday_from_ek(unsafe { day.dayOfTheWeek() })?,
+                        week: unsafe { day.weekNumber() } as i64,
+                    })
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Some(RecurrenceRule {
+        frequency,
+        interval: unsafe { rule.interval() }.max(1) as usize,
+        end,
+        days_of_week,
+        days_of_month: numbers_from_ns(unsafe { rule.daysOfTheMonth() }),
+        months_of_year: numbers_from_ns(unsafe { rule.monthsOfTheYear() })
+            .into_iter()
+            .filter_map(|m| u32::try_from(m).ok())
+            .collect(),
+        weeks_of_year: numbers_from_ns(unsafe { rule.weeksOfTheYear() }),
+        days_of_year: numbers_from_ns(unsafe { rule.daysOfTheYear() }),
+        set_positions: numbers_from_ns(unsafe { rule.setPositions() }),
+    })
+}
+
+// Helper to read an EventKit array of numbers, treating a missing one as empty
+fn numbers_from_ns(numbers: Option<Retained<NSArray<NSNumber>>>) -> Vec<i64> {
+    numbers
+        .map(|numbers| numbers.iter().map(|n| n.as_isize() as i64).collect())
+        .unwrap_or_default()
+}
+
+// Helper to build an EventKit array of numbers; EventKit wants nil for none
+fn numbers_to_ns(numbers: &[i64]) -> Option<Retained<NSArray<NSNumber>>> {
+    let numbers: Vec<Retained<NSNumber>> = numbers
+        .iter()
+        .map(|&n| NSNumber::new_isize(n as isize))
+        .collect();
+    (!numbers.is_empty()).then(|| NSArray::from_retained_slice(&numbers))
+}
+
+// Helper to build an EKRecurrenceRule
+fn recurrence_to_ek(rule: &RecurrenceRule) -> Retained<EKRecurrenceRule> {
+    let frequency = match rule.frequency {
+        RecurrenceFrequency::Daily => EKRecurrenceFrequency::Daily,
+        RecurrenceFrequency::Weekly => EKRecurrenceFrequency::Weekly,
+        RecurrenceFrequency::Monthly => EKRecurrenceFrequency::Monthly,
+        RecurrenceFrequency::Yearly => EKRecurrenceFrequency::Yearly,
+    };
+    let end = rule.end.as_ref().map(|end| match end {
+        RecurrenceEnd::Count(n) => unsafe { EKRecurrenceEnd::recurrenceEndWithOccurrenceCount(*n) },
+        RecurrenceEnd::Until(date) => unsafe {
+            EKRecurrenceEnd::recurrenceEndWithEndDate(&datetime_to_nsdate(*date))
+        },
+    });
+    let days: Vec<Retained<EKRecurrenceDayOfWeek>> = rule
+        .days_of_week
+        .iter()
+        .map(|day| unsafe {
+            EKRecurrenceDayOfWeek::dayOfWeek_weekNumber(
+                EKWeekday(day.weekday.number_from_sunday() as isize),
+                day.week as isize,
+            )
+        })
+        .collect();
+    let days = (!days.is_empty()).then(|| NSArray::from_retained_slice(&days));
+    let months: Vec<i64> = rule.months_of_year.iter().map(|&m| m as i64).collect();
+
+    unsafe {
+        EKRecurrenceRule::initRecurrenceWithFrequency_interval_daysOfTheWeek_daysOfTheMonth_monthsOfTheYear_weeksOfTheYear_daysOfTheYear_setPositions_end(
+            EKRecurrenceRule::alloc(),
+            frequency,
+            rule.interval.max(1) as isize,
+            days.as_deref(),
+            numbers_to_ns(&rule.days_of_month).as_deref(),
+            numbers_to_ns(&months).as_deref(),
+            numbers_to_ns(&rule.weeks_of_year).as_deref(),
+            numbers_to_ns(&rule.days_of_year).as_deref(),
+            numbers_to_ns(&rule.set_positions).as_deref(),
+            end.as_deref(),
+        )
+    }
+}
+
+// Helper to convert EventKit's Sunday-based weekday numbers
+fn weekday_from_ek(day: EKWeekday) -> Option<Weekday> {
+    match day.0 {
+        1 => Some(Weekday::Sun),
+        2..=7 => Weekday::try_from(day.0 as u8 - 2).ok(),
+        _ => None,
+    }
+}
+
+const RRULE_WEEKDAYS: [(&str, Weekday); 7] = [
+    ("MO", Weekday::Mon),
+    ("TU", Weekday::Tue),
+    ("WE", Weekday::Wed),
+    ("TH", Weekday::Thu),
+    ("FR", Weekday::Fri),
+    ("SA", Weekday::Sat),
+    ("SU", Weekday::Sun),
+];
+
+fn rrule_weekday_code(weekday: Weekday) -> &'static str {
+    RRULE_WEEKDAYS[weekday.num_days_from_monday() as usize].0
+}
+
+// Helper to parse an RRULE list of non-zero numbers such as "1,15,-1", each
+// within `-max..=max`
+fn parse_rrule_numbers(key: &str, value: &str, max: i64) -> Result<Vec<i64>> {
+    value
+        .split(',')
+        .map(|n| {
+            n.trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|n| *n != 0 && n.abs() <= max)
+                .ok_or_else(|| EventKitError::InvalidRRule(format!("bad {} '{}'", key, n)))
+        })
+        .collect()
+}
+
+// Helper to parse one BYDAY entry such as "MO", "2TU" or "-1FR"
+fn parse_rrule_day(day: &str) -> Option<RecurrenceDay> {
+    let day = day.trim();
+    let split = day.len().checked_sub(2)?;
+    let (week, code) = (day.get(..split)?, day.get(split..)?);
+    let weekday = RRULE_WEEKDAYS
+        .iter()
+        .find(|(c, _)| c.eq_ignore_ascii_case(code))?
+        .1;
+    let week = if week.is_empty() {
+        0
+    } else {
+        week.trim_start_matches('+').parse().ok()?
+    };
+    Some(RecurrenceDay { weekday, week })
+}
+
+// Helper to parse an RRULE UNTIL value: a UTC or local date-time, or a date
+fn parse_rrule_until(value: &str) -> Option<DateTime<Local>> {
+    if let Some(utc) = value.strip_suffix('Z') {
+        let naive = NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S").ok()?;
+        return Some(naive.and_utc().with_timezone(&Local));
+    }
+    if let Ok(naive) = NaiveDateTime::parse_from_str(value, "%Y%m%dT%H%M%S") {
+        return naive.and_local_timezone(Local).earliest();
+    }
+    let date = NaiveDate::parse_from_str(value, "%Y%m%d").ok()?;
+    date.and_hms_opt(23, 59, 59)?
+        .and_local_timezone(Local)
+        .earliest()
+}
+
+// Helper to find the occurrence after `due` under a simple rule, or `None`
+// if the rule has already run out
+fn next_occurrence(due: DateTime<Local>, rule: &RecurrenceRule) -> Option<DateTime<Local>> {
+    let interval = rule.interval as u32;
+    let naive = due.naive_local();
+    let next = match rule.frequency {
+        RecurrenceFrequency::Daily => naive.checked_add_days(Days::new(interval.into())),
+        RecurrenceFrequency::Weekly => naive.checked_add_days(Days::new(7 * u64::from(interval))),
+        RecurrenceFrequency::Monthly => naive.checked_add_months(Months::new(interval)),
+        RecurrenceFrequency::Yearly => naive.checked_add_months(Months::new(12 * interval)),
+    }?
+    .and_local_timezone(Local)
+    .earliest()?;
+
+    match &rule.end {
+        Some(RecurrenceEnd::Count(n)) if *n <= 1 => None,
+        Some(RecurrenceEnd::Until(until)) if next > *until => None,
+        _ => Some(next),
+    }
+}
+
+// Helper to read all alarms off an event or reminder
+fn alarms_from_item(item: &EKCalendarItem) -> Vec<Alarm> {
+    unsafe { item.alarms() }
+        .map(|alarms| alarms.iter().map(|a| alarm_from_ek(&a)).collect())
+        .unwrap_or_default()
+}
+
+// Helper to convert StructuredLocation to EKStructuredLocation
+fn structured_location_to_ek(location: &StructuredLocation) -> Retained<EKStructuredLocation> {
+    let ns_title = NSString::from_str(&location.title);
+    let ek_location = unsafe { EKStructuredLocation::locationWithTitle(&ns_title) };
+    let geo = unsafe {
+        CLLocation::initWithLatitude_longitude(
+            CLLocation::alloc(),
+            location.latitude,
+            location.longitude,
+        )
+    };
+    unsafe {
+        ek_location.setGeoLocation(Some(&geo));
+        ek_location.setRadius(location.radius);
+    }
+    ek_location
+}
+
+// Helper to convert EKStructuredLocation to StructuredLocation, if it has coordinates
+fn structured_location_from_ek(location: &EKStructuredLocation) -> Option<StructuredLocation> {
+    let geo = unsafe { location.geoLocation() }?;
+    let coordinate = unsafe { geo.coordinate() };
+
+    Some(StructuredLocation {
+        title: unsafe { location.title() }
+            .map(|t| t.to_string())
+            .unwrap_or_default(),
+        latitude: coordinate.latitude,
+        longitude: coordinate.longitude,
+        radius: unsafe { location.radius() },
+    })
+}
+
+// Helper to compute the start and end of a day beginning at `start_hour` in `tz`.
+// The end is one second before the next day's start, matching the inclusive
+// ranges used elsewhere. A start time skipped by a DST jump moves an hour later.
+fn day_bounds<Tz: TimeZone>(
+    date: NaiveDate,
+    tz: &Tz,
+    start_hour: u32,
+) -> Option<(DateTime<Tz>, DateTime<Tz>)> {
+    let boundary = |day: NaiveDate| {
+        let wall = day.and_hms_opt(start_hour, 0, 0)?;
+        resolve_local(tz, wall).or_else(|| resolve_local(tz, wall + Duration::hours(1)))
+    };
+
+    let start = boundary(date)?;
+    let end = boundary(date.succ_opt()?)? - Duration::seconds(1);
+    Some((start, end))
+}
+
+// Helper to apply skip/limit paging to a sorted list
+fn paginate<T>(items: Vec<T>, skip: usize, limit: Option<usize>) -> Vec<T> {
+    items
+        .into_iter()
+        .skip(skip)
+        .take(limit.unwrap_or(usize::MAX))
+        .collect()
+}
+
+// Helper to build an event predicate for one date range
+fn range_predicate(
+    store: &EKEventStore,
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    calendars: Option<&NSArray<EKCalendar>>,
+) -> Retained<NSPredicate> {
+    let start_date = datetime_to_nsdate(start);
+    let end_date = datetime_to_nsdate(end);
+    unsafe {
+        store.predicateForEventsWithStartDate_endDate_calendars(&start_date, &end_date, calendars)
+    }
+}
+
+// Helper to split `start..end` into back-to-back ranges that each fit in one
+// event predicate
+fn predicate_chunks(
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+    let mut chunks = Vec::new();
+    let mut chunk_start = start;
+    while chunk_start < end {
+        let chunk_end = (chunk_start + Duration::days(PREDICATE_MAX_DAYS)).min(end);
+        chunks.push((chunk_start, chunk_end));
+        chunk_start = chunk_end;
+    }
+    chunks
+}
+
+// Helper to drop repeated items, keeping the first of each key in order
+fn dedup_by_key<T, K: Eq + std::hash::Hash>(items: &mut Vec<T>, mut key: impl FnMut(&T) -> K) {
+    let mut seen = std::collections::HashSet::new();
+    items.retain(|item| seen.insert(key(item)));
+}
+
+// Helper to group events into agenda days. Timed events count towards the
+// day they start on, shifted back by `day_start_hour`; anything before
+// `first_day` is moved up to it.
+fn group_agenda(
+    events: Vec<EventItem>,
+    day_start_hour: u32,
+    first_day: Option<NaiveDate>,
+) -> Agenda {
+    let mut days: Vec<AgendaDay> = Vec::new();
+    for event in events {
+        let date = if event.all_day {
+            event.start_date.date_naive()
+        } else {
+            (event.start_date.naive_local() - Duration::hours(day_start_hour as i64)).date()
+        };
+        let date = first_day.map_or(date, |first| date.max(first));
+
+        let index = match days.iter().position(|d| d.date == date) {
+            Some(index) => index,
+            None => {
+                days.push(AgendaDay {
+                    date,
+                    all_day: Vec::new(),
+                    timed: Vec::new(),
+                });
+                days.len() - 1
+            }
+        };
+        if event.all_day {
+            days[index].all_day.push(event);
+        } else {
+            days[index].timed.push(event);
+        }
+    }
+    Agenda { days }
+}
+
+// Helper to split events into per-calendar groups, keeping the order of both
+fn group_by_calendar(
+    calendars: Vec<CalendarInfo>,
+    events: Vec<EventItem>,
+    include_empty: bool,
+) -> Vec<(CalendarInfo, Vec<EventItem>)> {
+    let mut by_calendar: HashMap<CalendarId, Vec<EventItem>> = HashMap::new();
+    for event in events {
+        if let Some(id) = event.calendar_identifier.clone() {
+            by_calendar.entry(id).or_default().push(event);
+        }
+    }
+
+    calendars
+        .into_iter()
+        .filter_map(|calendar| {
+            let events = by_calendar.remove(&calendar.identifier).unwrap_or_default();
+            (include_empty || !events.is_empty()).then_some((calendar, events))
+        })
+        .collect()
+}
+
+// Helper to pair up overlapping events; expects events sorted by start date
+fn conflicting_pairs(events: &[EventItem], include_all_day: bool) -> Vec<(EventItem, EventItem)> {
+    let candidates: Vec<&EventItem> = events
+        .iter()
+        .filter(|e| include_all_day || !e.all_day)
+        .filter(|e| e.start_date < e.end_date)
+        .collect();
+
+    let mut pairs = Vec::new();
+    for (i, a) in candidates.iter().enumerate() {
+        for b in &candidates[i + 1..] {
+            if b.start_date >= a.end_date {
+                break;
+            }
+            pairs.push(((*a).clone(), (*b).clone()));
+        }
+    }
+
+    pairs
+}
+
+// Helper to find the last calendar day a span covers, not counting an end
+// that falls exactly at midnight
+fn last_day(start: DateTime<Local>, end: DateTime<Local>) -> NaiveDate {
+    let ends_at_midnight = end.time() == NaiveTime::MIN;
+    if ends_at_midnight && end > start {
+        end.date_naive() - Duration::days(1)
+    } else {
+        end.date_naive()
+    }
+}
+
+// Helper to build the URL that tags an event with its upsert key,
+// percent-encoding everything but unreserved characters
+fn upsert_marker(key: &str) -> String {
+    let mut marker = format!("{}:", UPSERT_URL_SCHEME);
+    for byte in key.bytes() {
+        if byte.is_ascii_alphanumeric() || b"-._~".contains(&byte) {
+            marker.push(byte as char);
+        } else {
+            marker.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    marker
+}
+
+// Helper to merge events sorted by start into non-overlapping busy blocks
+// within `start..end`
+fn merge_busy(
+    events: &[EventItem],
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+) -> Vec<BusyBlock> {
+    let mut blocks: Vec<BusyBlock> = Vec::new();
+    for event in events {
+        let block_start = event.start_date.max(start);
+        let block_end = event.end_date.min(end);
+        if block_start >= block_end {
+            continue;
+        }
+
+        match blocks.last_mut() {
+            Some(last) if block_start <= last.end => last.end = last.end.max(block_end),
+            _ => blocks.push(BusyBlock {
+                start: block_start,
+                end: block_end,
+            }),
+        }
+    }
+    blocks
+}
+
+// Helper to check whether any of an event's text fields contain `query`,
+// which must already be lowercase
+fn text_matches(query: &str, title: &str, notes: Option<&str>, location: Option<&str>) -> bool {
+    [Some(title), notes, location]
+        .into_iter()
+        .flatten()
+        .any(|text| text.to_lowercase().contains(query))
+}
+
+// Helper to check whether an item last modified at `modified` changed after
+// `since`; items without a date count as changed
+fn changed_after(modified: Option<DateTime<Local>>, since: DateTime<Local>) -> bool {
+    modified.is_none_or(|modified| modified > since)
+}
+
+// Helper to check an EKEvent's text fields without converting it first
+fn ek_text_matches(event: &EKEvent, query: &str) -> bool {
+    let (notes, location) = unsafe { (event.notes(), event.location()) };
+    text_matches(
+        query,
+        &item_title(event),
+        notes.map(|n| n.to_string()).as_deref(),
+        location.map(|l| l.to_string()).as_deref(),
+    )
+}
+
+// Helper to find the gaps of at least `min_duration` between busy blocks
+// within `start..end`, limited to working hours when given
+fn free_slots(
+    busy: &[BusyBlock],
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+    min_duration: Duration,
+    working_hours: Option<&WorkingHours>,
+) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+    let windows = match working_hours {
+        None => vec![(start, end)],
+        Some(hours) => working_windows(hours, start, end),
+    };
+
+    let mut slots = Vec::new();
+    for (window_start, window_end) in windows {
+        let mut cursor = window_start;
+        for block in busy {
+            if block.end <= cursor || block.start >= window_end {
+                continue;
+            }
+            if block.start - cursor >= min_duration {
+                slots.push((cursor, block.start));
+            }
+            cursor = cursor.max(block.end);
+        }
+        if cursor < window_end && window_end - cursor >= min_duration {
+            slots.push((cursor, window_end));
+        }
+    }
+    slots
+}
+
+// Helper to list each working day's hours within `start..end`
+fn working_windows(
+    hours: &WorkingHours,
+    start: DateTime<Local>,
+    end: DateTime<Local>,
+) -> Vec<(DateTime<Local>, DateTime<Local>)> {
+    // Wall-clock times skipped by a DST change resolve an hour later
+    let at = |date: NaiveDate, time: NaiveTime| {
+        let wall = date.and_time(time);
+        resolve_local(&Local, wall).or_else(|| resolve_local(&Local, wall + Duration::hours(1)))
+    };
+
+    let mut windows = Vec::new();
+    let last = end.date_naive();
+    for date in start
+        .date_naive()
+        .iter_days()
+        .take_while(|date| *date <= last)
+    {
+        let weekend = matches!(date.weekday(), Weekday::Sat | Weekday::Sun);
+        if (hours.weekends || !weekend)
+            && let (Some(day_start), Some(day_end)) = (at(date, hours.start), at(date, hours.end))
+        {
+            let (window_start, window_end) = (day_start.max(start), day_end.min(end));
+            if window_start < window_end {
+                windows.push((window_start, window_end));
+            }
+        }
+    }
+    windows
+}
+
+// Helper to convert chrono DateTime to NSDate
+fn datetime_to_nsdate(dt: DateTime<Local>) -> Retained<NSDate> {
+    let timestamp = dt.timestamp() as f64;
+    NSDate::dateWithTimeIntervalSince1970(timestamp)
+}
+
+// Helper to convert NSDateComponents to chrono DateTime using the user's calendar
+fn components_to_datetime(components: &NSDateComponents) -> Option<DateTime<Local>> {
+    let date = NSCalendar::currentCalendar().dateFromComponents(components)?;
+    Some(nsdate_to_datetime(&date))
+}
+
+// Helper to convert chrono DateTime to NSDateComponents, optionally dropping the time
+fn datetime_to_components(dt: DateTime<Local>, with_time: bool) -> Retained<NSDateComponents> {
+    let components = NSDateComponents::new();
+    components.setYear(dt.year() as isize);
+    components.setMonth(dt.month() as isize);
+    components.setDay(dt.day() as isize);
+    if with_time {
+        components.setHour(dt.hour() as isize);
+        components.setMinute(dt.minute() as isize);
+    }
+    components
+}
+
+// Helper to find when a due date becomes overdue: its time, or the end of
+// the day for date-only due dates
+fn overdue_from(components: &NSDateComponents) -> Option<DateTime<Local>> {
+    let due = components_to_datetime(components)?;
+    Some(overdue_deadline(due, components_have_time(components)))
+}
+
+fn overdue_deadline(due: DateTime<Local>, has_time: bool) -> DateTime<Local> {
+    if has_time {
+        due
+    } else {
+        due + Duration::days(1)
+    }
+}
+
+// Helper to tell timed due dates from date-only ones
+fn components_have_time(components: &NSDateComponents) -> bool {
+    components.hour() != NSDateComponentUndefined
+}
+
+// Helper to convert NSDate to chrono DateTime
+fn nsdate_to_datetime(date: &NSDate) -> DateTime<Local> {
+    let timestamp = date.timeIntervalSince1970();
+    Local.timestamp_opt(timestamp as i64, 0).unwrap()
+}
+
+// ============================================================================
+// Sorting
+// ============================================================================
+
+/// The order to sort fetched events or reminders in
+///
+/// Reminders use their due date in place of a start date. Items without a
+/// due date always sort last, whichever direction is chosen.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortOrder {
+    /// Earliest first (the default for events); reminders sort by due
+    /// date, undated last
+    #[default]
+    StartAsc,
+    /// Latest first; reminders sort by due date, undated last
+    StartDesc,
+    /// Alphabetically by title, ignoring case
+    TitleAsc,
+    /// Highest priority first, then earliest due date; events sort by start
+    PriorityThenDue,
+    /// Most recently created first, undated last
+    CreatedDesc,
+    /// Most recently changed first, undated last
+    ModifiedDesc,
+    /// Keep the order EventKit returned
+    None,
+}
+
+impl SortOrder {
+    /// Sorts events in place
+    pub fn sort_events(self, events: &mut [EventItem]) {
+        match self {
+            SortOrder::StartAsc | SortOrder::PriorityThenDue => {
+                events.sort_by_key(|e| e.start_date)
+            }
+            SortOrder::StartDesc => events.sort_by_key(|e| std::cmp::Reverse(e.start_date)),
+            SortOrder::TitleAsc => events.sort_by_key(|e| e.title.to_lowercase()),
+            SortOrder::CreatedDesc => {
+                events.sort_by(|a, b| cmp_newest(a.creation_date, b.creation_date))
+            }
+            SortOrder::ModifiedDesc => {
+                events.sort_by(|a, b| cmp_newest(a.last_modified_date, b.last_modified_date))
+            }
+            SortOrder::None => {}
+        }
+    }
+
+    /// Sorts reminders in place
+    pub fn sort_reminders(self, reminders: &mut [ReminderItem]) {
+        match self {
+            SortOrder::TitleAsc => reminders.sort_by_key(|r| r.title.to_lowercase()),
+            _ => {
+                if let Some(cmp) = self.reminder_cmp() {
+                    reminders.sort_by(cmp);
+                }
+            }
+        }
+    }
+
+    /// Sorts reminders and keeps only the first `limit`
+    ///
+    /// Cheaper than sorting everything and truncating when `limit` is much
+    /// smaller than the list: only the kept reminders are fully sorted.
+    /// Reminders that compare equal at the cut-off may be picked in any order.
+    pub fn sort_reminders_limited(self, reminders: &mut Vec<ReminderItem>, limit: usize) {
+        if let Some(cmp) = self.reminder_cmp()
+            && limit > 0
+            && limit < reminders.len()
+        {
+            reminders.select_nth_unstable_by(limit - 1, cmp);
+        }
+        reminders.truncate(limit);
+        self.sort_reminders(reminders);
+    }
+
+    // Helper to get the reminder comparison for this order, if any
+    fn reminder_cmp(self) -> Option<fn(&ReminderItem, &ReminderItem) -> std::cmp::Ordering> {
+        match self {
+            SortOrder::StartAsc => Some(|a, b| cmp_due(a, b, false)),
+            SortOrder::StartDesc => Some(|a, b| cmp_due(a, b, true)),
+            SortOrder::TitleAsc => Some(|a, b| a.title.to_lowercase().cmp(&b.title.to_lowercase())),
+            SortOrder::PriorityThenDue => Some(|a, b| {
+                priority_rank(a.priority)
+                    .cmp(&priority_rank(b.priority))
+                    .then_with(|| cmp_due(a, b, false))
+            }),
+            SortOrder::CreatedDesc => Some(|a, b| cmp_newest(a.creation_date, b.creation_date)),
+            SortOrder::ModifiedDesc => {
+                Some(|a, b| cmp_newest(a.last_modified_date, b.last_modified_date))
+            }
+            SortOrder::None => None,
+        }
+    }
+}
+
+// Helper to order timestamps newest first, with missing ones last
+fn cmp_newest(a: Option<DateTime<Local>>, b: Option<DateTime<Local>>) -> std::cmp::Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => b.cmp(&a),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+// Helper to order reminders by due date, with undated reminders last
+fn cmp_due(a: &ReminderItem, b: &ReminderItem, descending: bool) -> std::cmp::Ordering {
+    match (a.due_date, b.due_date) {
+        (Some(a), Some(b)) if descending => b.cmp(&a),
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => std::cmp::Ordering::Less,
+        (None, Some(_)) => std::cmp::Ordering::Greater,
+        (None, None) => std::cmp::Ordering::Equal,
+    }
+}
+
+// Helper to rank priorities so 1 (high) comes first and 0 (none) comes last
+fn priority_rank(priority: Priority) -> u16 {
+    match priority.value() {
+        0 => u16::MAX,
+        value => value.into(),
+    }
+}
+
+// Helper for the default reminder order: incomplete first, then due date, then priority
+fn sort_reminders_default(reminders: &mut [ReminderItem]) {
+    reminders.sort_by(|a, b| {
+        a.completed
+            .cmp(&b.completed)
+            .then_with(|| cmp_due(a, b, false))
+            .then_with(|| priority_rank(a.priority).cmp(&priority_rank(b.priority)))
+    });
+}
+
+// ============================================================================
+// Date parsing
+// ============================================================================
+
+/// Parses a user-supplied date/time relative to the current local time
+///
+/// See [`parse_datetime_from`] for the accepted formats.
+pub fn parse_datetime(input: &str) -> Option<DateTime<Local>> {
+    parse_datetime_from(input, Local::now())
+}
+
+/// Parses a user-supplied date/time relative to `now`, in `now`'s time zone
+///
+/// Accepted formats (case-insensitive):
+/// - `YYYY-MM-DD HH:MM` and `YYYY-MM-DD`
+/// - `MM/DD/YYYY HH:MM` and `MM/DD/YYYY`
+/// - `today`, `tomorrow`, or a weekday name (`friday`, `fri`), optionally
+///   followed by `HH:MM`. A weekday always means the next such day after
+///   today, never today itself.
+/// - Relative offsets from `now`: `+30m`, `+2h`, `+1d`, `+1w`
+///
+/// Dates without a time resolve to midnight. A wall-clock time that occurs
+/// twice (when clocks fall back) resolves to the earlier instant; one that
+/// doesn't exist (when clocks spring forward) returns `None`.
+pub fn parse_datetime_from<Tz: TimeZone>(input: &str, now: DateTime<Tz>) -> Option<DateTime<Tz>> {
+    let input = input.trim().to_lowercase();
+
+    if let Some(offset) = input.strip_prefix('+') {
+        return parse_offset(offset).map(|d| now + d);
+    }
+
+    for format in ["%Y-%m-%d %H:%M", "%m/%d/%Y %H:%M"] {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(&input, format) {
+            return resolve_local(&now.timezone(), dt);
+        }
+    }
+
+    for format in ["%Y-%m-%d", "%m/%d/%Y"] {
+        if let Ok(date) = NaiveDate::parse_from_str(&input, format) {
+            return resolve_local(&now.timezone(), date.and_time(NaiveTime::MIN));
+        }
+    }
+
+    let (day, time) = match input.split_once(char::is_whitespace) {
+        Some((day, time)) => (day, Some(time.trim())),
+        None => (input.as_str(), None),
+    };
+
+    let today = now.date_naive();
+    let date = match day {
+        "today" => today,
+        "tomorrow" => today.succ_opt()?,
+        _ => {
+            let weekday = day.parse::<Weekday>().ok()?;
+            let ahead =
+                (weekday.num_days_from_monday() + 7 - today.weekday().num_days_from_monday()) % 7;
+            let ahead = if ahead == 0 { 7 } else { ahead };
+            today + Duration::days(ahead.into())
+        }
+    };
+
+    let time = match time {
+        Some(t) => NaiveTime::parse_from_str(t, "%H:%M").ok()?,
+        None => NaiveTime::MIN,
+    };
+
+    resolve_local(&now.timezone(), date.and_time(time))
+}
+
+/// Parses a user-supplied duration such as `30m`, `+2h`, `1d` or `-1w`
+///
+/// Uses the units of [`parse_datetime`]'s relative offsets: minutes, hours,
+/// days and weeks. A leading `-` gives a negative duration.
+pub fn parse_duration(input: &str) -> Option<Duration> {
+    let input = input.trim().to_lowercase();
+    match input.strip_prefix('-') {
+        Some(rest) => parse_offset(rest.strip_prefix('+').unwrap_or(rest)).map(|d| -d),
+        None => parse_offset(input.strip_prefix('+').unwrap_or(&input)),
+    }
+}
+
+// Helper to parse the `30m`/`2h`/`1d`/`1w` part of a relative offset
+fn parse_offset(s: &str) -> Option<Duration> {
+    let unit = s.chars().last()?;
+    let amount: i64 = s[..s.len() - unit.len_utf8()].parse().ok()?;
+
+    match unit {
+        'm' => Duration::try_minutes(amount),
+        'h' => Duration::try_hours(amount),
+        'd' => Duration::try_days(amount),
+        'w' => Duration::try_weeks(amount),
+        _ => None,
+    }
+}
+
+// Helper to resolve a wall-clock time, preferring the earlier of two candidates
+fn resolve_local<Tz: TimeZone>(tz: &Tz, dt: NaiveDateTime) -> Option<DateTime<Tz>> {
+    tz.from_local_datetime(&dt).earliest()
+}
+
+// ============================================================================
+// Completion waits
+// ============================================================================
+
+// How long blocking calls wait for a completion handler by default
+const DEFAULT_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(30);
+
+// Helper to block until a completion handler fills `slot`, giving up after
+// `timeout`. The slot is shared with the handler through an `Arc`, so a
+// handler that fires after we've given up still writes into live memory and
+// its result is simply dropped.
+fn wait_for_completion<T>(
+    slot: &(Mutex<Option<T>>, Condvar),
+    timeout: std::time::Duration,
+) -> Result<T> {
+    let (lock, cvar) = slot;
+    let guard = lock.lock().unwrap();
+    let (mut guard, _) = cvar
+        .wait_timeout_while(guard, timeout, |result| result.is_none())
+        .unwrap();
+    guard.take().ok_or(EventKitError::Timeout(timeout))
+}
+
+// Helper to hand each item from `rx` to `f` until the sender hangs up. The
+// timeout only covers waiting for the next item, so a slow `f` never trips it.
+fn drain_channel<T>(
+    rx: &mpsc::Receiver<T>,
+    timeout: std::time::Duration,
+    mut f: impl FnMut(T),
+) -> Result<()> {
+    loop {
+        match rx.recv_timeout(timeout) {
+            Ok(item) => f(item),
+            Err(mpsc::RecvTimeoutError::Disconnected) => return Ok(()),
+            Err(mpsc::RecvTimeoutError::Timeout) => return Err(EventKitError::Timeout(timeout)),
+        }
+    }
+}
+
+// ============================================================================
+// Save retries
+// ============================================================================
+
+/// How a manager retries saves that fail with a transient error
+///
+/// Only errors that are expected to clear up on their own are retried:
+/// EventKit's internal failure and the underlying database being locked or
+/// busy, which iCloud syncs occasionally cause. Permission and validation
+/// errors fail straight away. The default makes no retries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry; each later retry waits twice as long
+    pub initial_backoff: std::time::Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 0,
+            initial_backoff: std::time::Duration::from_millis(100),
+        }
+    }
+}
+
+// NSCocoaErrorDomain codes for a locked or busy store: NSFileLockingError,
+// NSPersistentStoreSaveConflictsError and NSSQLiteError
+const TRANSIENT_COCOA_CODES: [isize; 3] = [255, 134050, 134180];
+
+// Helper to decide whether a save error is worth retrying
+fn is_transient_error(error: &Retained<NSError>) -> bool {
+    let domain = error.domain();
+    let code = error.code();
+    if unsafe { EKErrorDomain }.is_some_and(|ek| *domain == *ek) {
+        code == EKErrorCode::InternalFailure.0
+    } else if *domain == *unsafe { NSCocoaErrorDomain } {
+        TRANSIENT_COCOA_CODES.contains(&code)
+    } else {
+        false
+    }
+}
+
+// Helper to run `op`, retrying transient failures with exponential backoff
+fn with_retry<T, E>(
+    policy: &RetryPolicy,
+    is_transient: impl Fn(&E) -> bool,
+    mut op: impl FnMut() -> std::result::Result<T, E>,
+) -> std::result::Result<T, E> {
+    let mut backoff = policy.initial_backoff;
+    let mut retries = 0;
+    loop {
+        match op() {
+            Err(e) if retries < policy.max_retries && is_transient(&e) => {
+                std::thread::sleep(backoff);
+                backoff *= 2;
+                retries += 1;
+            }
+            result => return result,
+        }
+    }
+}
+
+// ============================================================================
+// Reminder store abstraction
+// ============================================================================
+
+/// The core reminder operations, abstracted over the backing store
+///
+/// [`RemindersManager`] implements this against the live EventKit store. With
+/// the `test-util` feature, `MockStore` provides an in-memory implementation
+/// so code written against `&impl ReminderStore` can be tested without
+/// EventKit or granted permissions.
+pub trait ReminderStore {
+    /// Fetches reminders, optionally limited to the given list titles
+    fn fetch_reminders(&self, calendar_titles: Option<&[&str]>) -> Result<Vec<ReminderItem>>;
+
+    /// Gets a reminder by its identifier
+    fn get_reminder(&self, identifier: &ReminderId) -> Result<ReminderItem>;
+
+    /// Creates a new reminder
+    fn create_reminder(
+        &self,
+        title: &str,
+        notes: Option<&str>,
+        calendar_title: Option<&str>,
+        priority: Option<Priority>,
+        due_date: Option<DateTime<Local>>,
+    ) -> Result<ReminderItem>;
+
+    /// Updates an existing reminder; `due_date` is `Some(None)` to clear it
+    fn update_reminder(
+        &self,
+        identifier: &ReminderId,
+        title: Option<&str>,
+        notes: Option<&str>,
+        completed: Option<bool>,
+        priority: Option<Priority>,
+        due_date: Option<Option<DateTime<Local>>>,
+    ) -> Result<ReminderItem>;
+
+    /// Deletes a reminder
+    fn delete_reminder(&self, identifier: &ReminderId) -> Result<()>;
+}
+
+impl ReminderStore for RemindersManager {
+    fn fetch_reminders(&self, calendar_titles: Option<&[&str]>) -> Result<Vec<ReminderItem>> {
+        RemindersManager::fetch_reminders(self, calendar_titles)
+    }
+
+    fn get_reminder(&self, identifier: &ReminderId) -> Result<ReminderItem> {
+        RemindersManager::get_reminder(self, identifier)
+    }
+
+    fn create_reminder(
+        &self,
+        title: &str,
+        notes: Option<&str>,
+        calendar_title: Option<&str>,
+        priority: Option<Priority>,
+        due_date: Option<DateTime<Local>>,
+    ) -> Result<ReminderItem> {
+        RemindersManager::create_reminder(
+            self,
+            title,
+            notes,
+            calendar_title,
+            priority,
+            &[],
+            due_date,
+            None,
+        )
+    }
+
+    fn update_reminder(
+        &self,
+        identifier: &ReminderId,
+        title: Option<&str>,
+        notes: Option<&str>,
+        completed: Option<bool>,
+        priority: Option<Priority>,
+        due_date: Option<Option<DateTime<Local>>>,
+    ) -> Result<ReminderItem> {
+        RemindersManager::update_reminder(
+            self, identifier, title, notes, completed, priority, due_date, None,
+        )
+    }
+
+    fn delete_reminder(&self, identifier: &ReminderId) -> Result<()> {
+        RemindersManager::delete_reminder(self, identifier)
+    }
+}
+
+/// An in-memory [`ReminderStore`] for tests
+///
+/// Reminders get sequential identifiers (`mock-1`, `mock-2`, ...) and are
+/// created in the "Reminders" list unless another title is given.
+#[cfg(any(test, feature = "test-util"))]
+#[derive(Debug, Default)]
+pub struct MockStore {
+    reminders: Mutex<Vec<ReminderItem>>,
+    next_id: std::sync::atomic::AtomicUsize,
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl MockStore {
+    /// Creates an empty store
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a store pre-populated with the given reminders
+    pub fn with_reminders(reminders: Vec<ReminderItem>) -> Self {
+        Self {
+            reminders: Mutex::new(reminders),
+            next_id: Default::default(),
+        }
+    }
+
+    // Helper to lock the reminder list, recovering from a poisoned lock
+    fn items(&self) -> std::sync::MutexGuard<'_, Vec<ReminderItem>> {
+        self.reminders.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[cfg(any(test, feature = "test-util"))]
+impl ReminderStore for MockStore {
+    fn fetch_reminders(&self, calendar_titles: Option<&[&str]>) -> Result<Vec<ReminderItem>> {
+        Ok(self
+            .items()
+            .iter()
+            .filter(|r| match (calendar_titles, &r.calendar_title) {
+                (None, _) => true,
+                (Some(titles), Some(title)) => titles.contains(&title.as_str()),
+                (Some(_), None) => false,
+            })
+            .cloned()
+            .collect())
+    }
+
+    fn get_reminder(&self, identifier: &ReminderId) -> Result<ReminderItem> {
+        self.items()
+            .iter()
+            .find(|r| r.identifier == *identifier)
+            .cloned()
+            .ok_or_else(|| EventKitError::ItemNotFound(identifier.to_string()))
+    }
+
+    fn create_reminder(
+        &self,
+        title: &str,
+        notes: Option<&str>,
+        calendar_title: Option<&str>,
+        priority: Option<Priority>,
+        due_date: Option<DateTime<Local>>,
+    ) -> Result<ReminderItem> {
+        priority.map(check_priority).transpose()?;
+        let id = self
+            .next_id
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed)
+            + 1;
+        let calendar_title = calendar_title.unwrap_or("Reminders");
+        let item = ReminderItem {
+            identifier: format!("mock-{}", id).into(),
+            external_identifier: None,
+            title: title.to_string(),
+            notes: notes.map(str::to_string),
+            completed: false,
+            completion_date: None,
+            creation_date: Some(Local::now()),
+            last_modified_date: Some(Local::now()),
+            priority: priority.unwrap_or_default(),
+            calendar_title: Some(calendar_title.to_string()),
+            calendar_identifier: Some(format!("mock-calendar-{}", calendar_title).into()),
+            url: None,
+            alarms: Vec::new(),
+            due_date,
+            recurrence_rules: Vec::new(),
+        };
+        self.items().push(item.clone());
+        Ok(item)
+    }
+
+    fn update_reminder(
+        &self,
+        identifier: &ReminderId,
+        title: Option<&str>,
+        notes: Option<&str>,
+        completed: Option<bool>,
+        priority: Option<Priority>,
+        due_date: Option<Option<DateTime<Local>>>,
+    ) -> Result<ReminderItem> {
+        priority.map(check_priority).transpose()?;
+        let mut items = self.items();
+        let item = items
+            .iter_mut()
+            .find(|r| r.identifier == *identifier)
+            .ok_or_else(|| EventKitError::ItemNotFound(identifier.to_string()))?;
+        if let Some(title) = title {
+            item.title = title.to_string();
+        }
+        if let Some(notes) = notes {
+            item.notes = Some(notes.to_string());
+        }
+        if let Some(completed) = completed {
+            item.completed = completed;
+            item.completion_date = completed.then(Local::now);
+        }
+        if let Some(priority) = priority {
+            item.priority = priority;
+        }
+        if let Some(due_date) = due_date {
+            item.due_date = due_date;
+        }
+        item.last_modified_date = Some(Local::now());
+        Ok(item.clone())
+    }
+
+    fn delete_reminder(&self, identifier: &ReminderId) -> Result<()> {
+        let mut items = self.items();
+        let index = items
+            .iter()
+            .position(|r| r.identifier == *identifier)
+            .ok_or_else(|| EventKitError::ItemNotFound(identifier.to_string()))?;
+        items.remove(index);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// Shared event store
+// ============================================================================
+
+/// One event store shared by a reminders and an events manager
+///
+/// EventKit recommends a single long-lived `EKEventStore` per app: each store
+/// keeps its own copy of calendars, sources and cached items, and posts its
+/// own change notifications. Apps using both reminders and events should
+/// create one `EventKit` rather than a standalone [`RemindersManager`] and
+/// [`EventsManager`], halving that memory and ensuring a change saved
+/// through one manager is immediately visible to the other.
+///
+/// Reminders and events are still authorized separately, so each manager
+/// needs its own [`request_access`](RemindersManager::request_access).
+pub struct EventKit {
+    reminders: RemindersManager,
+    events: EventsManager,
+}
+
+impl EventKit {
+    /// Creates a new event store and both managers on top of it
+    pub fn new() -> Self {
+        let store = unsafe { EKEventStore::new() };
+        Self {
+            reminders: RemindersManager::with_store(store.clone()),
+            events: EventsManager::with_store(store),
+        }
+    }
+
+    /// The reminders manager backed by the shared store
+    pub fn reminders(&self) -> &RemindersManager {
+        &self.reminders
+    }
+
+    /// The reminders manager, for changing its settings
+    pub fn reminders_mut(&mut self) -> &mut RemindersManager {
+        &mut self.reminders
+    }
+
+    /// The events manager backed by the shared store
+    pub fn events(&self) -> &EventsManager {
+        &self.events
+    }
+
+    /// The events manager, for changing its settings
+    pub fn events_mut(&mut self) -> &mut EventsManager {
+        &mut self.events
+    }
+
+    /// Checks everything access to reminders and events depends on
+    ///
+    /// Never prompts for permission, so it's safe to call when working out
+    /// why nothing shows up.
+    pub fn diagnostics(&self) -> Diagnostics {
+        Diagnostics {
+            macos_version: format_os_version(os_version()),
+            authorization: authorization_summary(),
+            reminder_lists: self.reminders.list_calendars().ok().map(|c| c.len()),
+            event_calendars: self.events.list_calendars().ok().map(|c| c.len()),
+            has_default_reminder_list: self.reminders.default_calendar().is_ok(),
+            has_default_calendar: self.events.default_calendar().is_ok(),
+        }
+    }
+}
+
+/// A self-check of EventKit access, from [`EventKit::diagnostics`]
+///
+/// Calendar counts are `None` when access to that kind of calendar hasn't
+/// been granted, since EventKit hides calendars until it is; the defaults
+/// then read as missing too.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostics {
+    /// The running macOS version (e.g. "14.4.1")
+    pub macos_version: String,
+    /// Authorization for both entity types, and full-access support
+    pub authorization: AuthorizationSummary,
+    /// How many reminder lists there are
+    pub reminder_lists: Option<usize>,
+    /// How many event calendars there are
+    pub event_calendars: Option<usize>,
+    /// Whether new reminders have a default list to go into
+    pub has_default_reminder_list: bool,
+    /// Whether new events have a default calendar to go into
+    pub has_default_calendar: bool,
+}
+
+impl Diagnostics {
+    /// Whether both kinds of items can be read and have somewhere to be saved
+    pub fn is_healthy(&self) -> bool {
+        self.reminder_lists.is_some_and(|n| n > 0)
+            && self.event_calendars.is_some_and(|n| n > 0)
+            && self.has_default_reminder_list
+            && self.has_default_calendar
+    }
+}
+
+impl Default for EventKit {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// ============================================================================
+// Change notifications
+// ============================================================================
+
+/// A registered change callback; see [`RemindersManager::on_change`] and
+/// [`EventsManager::on_change`]
+///
+/// The callback is unregistered when this is dropped.
+pub struct ChangeSubscription {
+    observer: Retained<ProtocolObject<dyn NSObjectProtocol>>,
+}
+
+impl ChangeSubscription {
+    fn new(store: &EKEventStore, callback: impl Fn() + Send + Sync + 'static) -> Self {
+        let block = RcBlock::new(move |_notification: NonNull<NSNotification>| callback());
+
+        let observer = unsafe {
+            NSNotificationCenter::defaultCenter().addObserverForName_object_queue_usingBlock(
+                Some(EKEventStoreChangedNotification),
+                Some(store),
+                None,
+                &block,
+            )
+        };
+
+        Self { observer }
+    }
+}
+
+impl Drop for ChangeSubscription {
+    fn drop(&mut self) {
+        unsafe {
+            NSNotificationCenter::defaultCenter().removeObserver(self.observer.as_ref());
+        }
+    }
+}
+
+impl std::fmt::Debug for ChangeSubscription {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ChangeSubscription").finish_non_exhaustive()
+    }
+}
+
+/// Runs the current thread's run loop for about `timeout`
+///
+/// Command-line tools have no run loop of their own, so call this in a loop
+/// while waiting for [`ChangeSubscription`] callbacks.
+pub fn run_loop_for(timeout: std::time::Duration) {
+    let deadline = std::time::Instant::now() + timeout;
+    let limit = NSDate::dateWithTimeIntervalSinceNow(timeout.as_secs_f64());
+    NSRunLoop::currentRunLoop().runUntilDate(&limit);
+
+    // runUntilDate returns straight away when the loop has no input sources;
+    // sleep out the rest so callers polling in a loop don't spin
+    std::thread::sleep(deadline.saturating_duration_since(std::time::Instant::now()));
+}
+
+// ============================================================================
+// Calendar cache
+// ============================================================================
+
+/// Cached `EKCalendar` handles for one entity type, keyed by identifier.
+///
+/// The cache is lazily populated from `calendarsForEntityType` (plus the
+/// default calendar for new items) and dropped whenever the store posts
+/// `EKEventStoreChangedNotification`, so a calendar added, renamed or removed
+/// elsewhere is picked up on the next lookup.
+struct CalendarCache {
+    store: Retained<EKEventStore>,
+    entity_type: EKEntityType,
+    entries: RefCell<Option<CachedCalendars>>,
+    stale: Arc<AtomicBool>,
+    _changes: ChangeSubscription,
+}
+
+struct CachedCalendars {
+    by_id: HashMap<String, Retained<EKCalendar>>,
+    // Identifiers in EventKit's order, so title lookups stay deterministic
+    order: Vec<String>,
+    // Calendar new items go into when none is given
+    default: Option<Retained<EKCalendar>>,
+}
+
+impl CalendarCache {
+    fn new(store: &EKEventStore, entity_type: EKEntityType) -> Self {
+        let stale = Arc::new(AtomicBool::new(false));
+        let stale_clone = Arc::clone(&stale);
+
+        let changes = ChangeSubscription::new(store, move || {
+            stale_clone.store(true, Ordering::Release);
+        });
+
+        Self {
+            store: store.retain(),
+            entity_type,
+            entries: RefCell::new(None),
+            stale,
+            _changes: changes,
+        }
+    }
+
+    fn with_entries<R>(&self, f: impl FnOnce(&CachedCalendars) -> R) -> R {
+        if self.stale.swap(false, Ordering::AcqRel) {
+            self.entries.borrow_mut().take();
+        }
+
+        let mut entries = self.entries.borrow_mut();
+        let entries = entries.get_or_insert_with(|| {
+            let calendars = unsafe { self.store.calendarsForEntityType(self.entity_type) };
+            let (by_id, order) = index_by_id(calendars.iter().map(|cal| {
+                (
+                    unsafe { cal.calendarIdentifier() }.to_string(),
+                    cal.retain(),
+                )
+            }));
+            let default = if self.entity_type == EKEntityType::Reminder {
+                unsafe { self.store.defaultCalendarForNewReminders() }
+            } else {
+                unsafe { self.store.defaultCalendarForNewEvents() }
+            };
+            CachedCalendars {
+                by_id,
+                order,
+                default,
+            }
+        });
+
+        f(entries)
+    }
+
+    fn invalidate(&self) {
+        self.entries.borrow_mut().take();
+    }
+
+    fn by_id(&self, identifier: &str) -> Option<Retained<EKCalendar>> {
+        self.with_entries(|entries| entries.by_id.get(identifier).cloned())
+    }
+
+    fn default(&self) -> Option<Retained<EKCalendar>> {
+        self.with_entries(|entries| entries.default.clone())
+    }
+
+    fn by_title(&self, title: &str) -> Option<Retained<EKCalendar>> {
+        self.matching_titles(&[title]).into_iter().next()
+    }
+
+    // Looks up a calendar by title, falling back to case-insensitive and then
+    // prefix matches when `fuzzy` is set
+    fn resolve_title(&self, query: &str, fuzzy: bool) -> Result<Retained<EKCalendar>> {
+        if let Some(calendar) = self.by_title(query) {
+            return Ok(calendar);
+        }
+        if !fuzzy {
+            return Err(EventKitError::CalendarNotFound(query.to_string()));
+        }
+
+        self.with_entries(|entries| {
+            let candidates: Vec<(&String, String)> = entries
+                .order
+                .iter()
+                .filter_map(|id| Some((id, unsafe { entries.by_id.get(id)?.title() }.to_string())))
+                .collect();
+            let titles: Vec<&str> = candidates.iter().map(|(_, t)| t.as_str()).collect();
+
+            match fuzzy_title_matches(query, &titles).as_slice() {
+                [] => Err(EventKitError::CalendarNotFound(query.to_string())),
+                [index] => Ok(entries.by_id[candidates[*index].0].clone()),
+                indices => Err(EventKitError::AmbiguousCalendar {
+                    query: query.to_string(),
+                    matches: indices
+                        .iter()
+                        .map(|&i| format!("{} ({})", candidates[i].1, candidates[i].0))
+                        .collect(),
+                }),
+            }
+        })
+    }
+
+    fn matching_titles(&self, titles: &[&str]) -> Vec<Retained<EKCalendar>> {
+        self.with_entries(|entries| {
+            entries
+                .order
+                .iter()
+                .filter_map(|id| entries.by_id.get(id))
+                .filter(|cal| {
+                    let title = unsafe { cal.title() }.to_string();
+                    titles.iter().any(|t| *t == title)
+                })
+                .cloned()
+                .collect()
+        })
+    }
+}
+
+// Helper to index items by identifier, keeping the first-seen order of
+// identifiers free of repeats so title lookups never return the same
+// calendar twice
+fn index_by_id<V>(
+    items: impl IntoIterator<Item = (String, V)>,
+) -> (HashMap<String, V>, Vec<String>) {
+    let mut by_id = HashMap::new();
+    let mut order = Vec::new();
+    for (id, item) in items {
+        if let std::collections::hash_map::Entry::Vacant(entry) = by_id.entry(id.clone()) {
+            entry.insert(item);
+            order.push(id);
+        }
+    }
+    (by_id, order)
+}
+
+// Helper to find the titles a query loosely matches: case-insensitive exact
+// matches if there are any, otherwise case-insensitive prefix matches
+fn fuzzy_title_matches(query: &str, titles: &[&str]) -> Vec<usize> {
+    let query = query.to_lowercase();
+    let lowered: Vec<String> = titles.iter().map(|t| t.to_lowercase()).collect();
+
+    let exact: Vec<usize> = (0..titles.len()).filter(|&i| lowered[i] == query).collect();
+    if !exact.is_empty() {
+        return exact;
+    }
+    (0..titles.len())
+        .filter(|&i| lowered[i].starts_with(&query))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    #[test]
+    fn test_authorization_status_display() {
+        assert_eq!(
+            format!("{}", AuthorizationStatus::NotDetermined),
+            "Not Determined"
+        );
+        assert_eq!(
+            format!("{}", AuthorizationStatus::FullAccess),
+            "Full Access"
+        );
+    }
+
+    #[test]
+    fn test_authorization_result() {
+        assert!(authorization_result(AuthorizationStatus::FullAccess).is_ok());
+        assert!(authorization_result(AuthorizationStatus::WriteOnly).is_ok());
+        assert!(matches!(
+            authorization_result(AuthorizationStatus::NotDetermined),
+            Err(EventKitError::AuthorizationNotDetermined)
+        ));
+        assert!(matches!(
+            authorization_result(AuthorizationStatus::Denied),
+            Err(EventKitError::AuthorizationDenied)
+        ));
+        assert!(matches!(
+            authorization_result(AuthorizationStatus::Restricted),
+            Err(EventKitError::AuthorizationRestricted)
+        ));
+    }
+
+    #[test]
+    fn test_event_item_debug() {
+        let event = EventItem {
+            identifier: "test".into(),
+            external_identifier: None,
+            title: "Test Event".to_string(),
+            notes: None,
+            has_notes: false,
+            location: None,
+            url: None,
+            start_date: Local::now(),
+            end_date: Local::now(),
+            all_day: false,
+            occurrence_date: None,
+            has_recurrence: false,
+            recurrence_rules: Vec::new(),
+            is_detached: false,
+            time_zone: None,
+            calendar_title: None,
+            calendar_identifier: None,
+            structured_location: None,
+            alarms: Vec::new(),
+            has_alarms: false,
+            attendees: Vec::new(),
+            has_attendees: false,
+            my_participation_status: None,
+            organizer: None,
+            availability: EventAvailability::Busy,
+            status: EventStatus::None,
+            creation_date: None,
+            last_modified_date: None,
+        };
+        assert!(format!("{:?}", event).contains("Test Event"));
+    }
+
+    fn test_event(title: &str, start: (u32, u32), end: (u32, u32), all_day: bool) -> EventItem {
+        EventItem {
+            identifier: title.into(),
+            external_identifier: None,
+            title: title.to_string(),
+            notes: None,
+            has_notes: false,
+            location: None,
+            url: None,
+            start_date: Local
+                .with_ymd_and_hms(2024, 6, 12, start.0, start.1, 0)
+                .unwrap(),
+            end_date: Local
+                .with_ymd_and_hms(2024, 6, 12, end.0, end.1, 0)
+                .unwrap(),
+            all_day,
+            occurrence_date: None,
+            has_recurrence: false,
+            recurrence_rules: Vec::new(),
+            is_detached: false,
+            time_zone: None,
+            calendar_title: None,
+            calendar_identifier: None,
+            structured_location: None,
+            alarms: Vec::new(),
+            has_alarms: false,
+            attendees: Vec::new(),
+            has_attendees: false,
+            my_participation_status: None,
+            organizer: None,
+            availability: EventAvailability::Busy,
+            status: EventStatus::None,
+            creation_date: None,
+            last_modified_date: None,
+        }
+    }
+
+    fn test_reminder(title: &str) -> ReminderItem {
+        ReminderItem {
+            identifier: title.into(),
+            external_identifier: None,
+            title: title.to_string(),
+            notes: None,
+            completed: false,
+            completion_date: None,
+            creation_date: None,
+            last_modified_date: None,
+            priority: Priority::default(),
+            calendar_title: None,
+            calendar_identifier: None,
+            url: None,
+            alarms: Vec::new(),
+            due_date: None,
+            recurrence_rules: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_conflicting_pairs() {
+        let events = vec![
+            test_event("standup", (9, 0), (9, 30), false),
+            test_event("review", (9, 15), (10, 0), false),
+            test_event("lunch", (12, 0), (13, 0), false),
+            test_event("call", (12, 30), (12, 45), false),
+        ];
+
+        let pairs: Vec<(String, String)> = conflicting_pairs(&events, false)
+            .into_iter()
+            .map(|(a, b)| (a.title, b.title))
+            .collect();
+
+        assert_eq!(
+            pairs,
+            vec![
+                ("standup".to_string(), "review".to_string()),
+                ("lunch".to_string(), "call".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_conflicting_pairs_edge_cases() {
+        let events = vec![
+            test_event("holiday", (0, 0), (23, 59), true),
+            test_event("first", (9, 0), (10, 0), false),
+            test_event("instant", (9, 30), (9, 30), false),
+            test_event("second", (10, 0), (11, 0), false),
+        ];
+
+        // Back-to-back and zero-length events don't conflict
+        assert!(conflicting_pairs(&events, false).is_empty());
+
+        // All-day events only count when asked for
+        assert_eq!(conflicting_pairs(&events, true).len(), 2);
+    }
+
+    #[test]
+    fn test_merge_busy() {
+        let at = |h, m| Local.with_ymd_and_hms(2024, 6, 12, h, m, 0).unwrap();
+        let events = vec![
+            test_event("early", (7, 0), (8, 30), false),
+            test_event("standup", (9, 0), (9, 30), false),
+            test_event("review", (9, 15), (10, 0), false),
+            test_event("follow-up", (10, 0), (10, 30), false),
+            test_event("instant", (12, 0), (12, 0), false),
+            test_event("late", (17, 30), (19, 0), false),
+        ];
+
+        let blocks: Vec<_> = merge_busy(&events, at(8, 0), at(18, 0))
+            .into_iter()
+            .map(|b| (b.start, b.end))
+            .collect();
+        assert_eq!(
+            blocks,
+            [
+                (at(8, 0), at(8, 30)),
+                (at(9, 0), at(10, 30)),
+                (at(17, 30), at(18, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_drain_channel_slow_callback() {
+        let timeout = std::time::Duration::from_millis(50);
+        let (tx, rx) = mpsc::sync_channel(1);
+        let sender = std::thread::spawn(move || {
+            for n in 0..3 {
+                tx.send(n).unwrap();
+            }
+        });
+
+        // Each callback takes longer than the whole timeout
+        let mut seen = Vec::new();
+        let result = drain_channel(&rx, timeout, |n| {
+            std::thread::sleep(timeout * 2);
+            seen.push(n);
+        });
+        sender.join().unwrap();
+        assert!(result.is_ok());
+        assert_eq!(seen, [0, 1, 2]);
+
+        // A sender that goes quiet still times out
+        let (_tx, rx) = mpsc::sync_channel::<i32>(1);
+        assert!(matches!(
+            drain_channel(&rx, timeout, |_| {}),
+            Err(EventKitError::Timeout(_))
+        ));
+    }
+
+    #[test]
+    fn test_changed_after() {
+        let at = |h| Local.with_ymd_and_hms(2024, 6, 12, h, 0, 0).unwrap();
+        assert!(changed_after(Some(at(10)), at(9)));
+        assert!(!changed_after(Some(at(9)), at(9)));
+        assert!(!changed_after(Some(at(8)), at(9)));
+        assert!(changed_after(None, at(9)));
+    }
+
+    #[test]
+    fn test_text_matches() {
+        assert!(text_matches("hotel", "Hotel check-in", None, None));
+        assert!(text_matches(
+            "abc123",
+            "Trip",
+            Some("Confirmation ABC123"),
+            None
+        ));
+        assert!(text_matches("main st", "Dinner", None, Some("12 Main St")));
+        assert!(!text_matches(
+            "hotel",
+            "Flight",
+            Some("Seat 12A"),
+            Some("Airport")
+        ));
+    }
+
+    #[test]
+    fn test_free_slots() {
+        let at = |d, h, m| Local.with_ymd_and_hms(2024, 6, d, h, m, 0).unwrap();
+        let busy = [
+            BusyBlock {
+                start: at(12, 9, 0),
+                end: at(12, 10, 30),
+            },
+            BusyBlock {
+                start: at(12, 11, 0),
+                end: at(12, 12, 0),
+            },
+            BusyBlock {
+                start: at(12, 16, 30),
+                end: at(12, 18, 0),
+            },
+        ];
+
+        let slots = free_slots(
+            &busy,
+            at(12, 8, 0),
+            at(12, 18, 0),
+            Duration::minutes(45),
+            None,
+        );
+        assert_eq!(
+            slots,
+            [
+                (at(12, 8, 0), at(12, 9, 0)),
+                (at(12, 12, 0), at(12, 16, 30))
+            ]
+        );
+
+        // 2024-06-14 is a Friday, so the weekend is skipped
+        let hours = WorkingHours::default();
+        let slots = free_slots(
+            &busy,
+            at(12, 0, 0),
+            at(17, 0, 0),
+            Duration::minutes(30),
+            Some(&hours),
+        );
+        assert_eq!(
+            slots,
+            [
+                (at(12, 10, 30), at(12, 11, 0)),
+                (at(12, 12, 0), at(12, 16, 30)),
+                (at(13, 9, 0), at(13, 17, 0)),
+                (at(14, 9, 0), at(14, 17, 0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_upsert_marker() {
+        assert_eq!(upsert_marker("ticket-42"), "eventkit-rs-key:ticket-42");
+        assert_eq!(upsert_marker("cal/é 1"), "eventkit-rs-key:cal%2F%C3%A9%201");
+    }
+
+    #[test]
+    fn test_overlap_mode() {
+        let at = |h, m| Local.with_ymd_and_hms(2024, 6, 12, h, m, 0).unwrap();
+        let straddling = test_event("straddling", (8, 0), (10, 0), false);
+        let inside = test_event("inside", (10, 0), (11, 0), false);
+        let (start, end) = (at(9, 0), at(12, 0));
+
+        assert!(OverlapMode::Overlaps.includes(&straddling, start, end));
+        assert!(!OverlapMode::StartsWithin.includes(&straddling, start, end));
+        assert!(!OverlapMode::ContainedWithin.includes(&straddling, start, end));
+
+        assert!(OverlapMode::StartsWithin.includes(&inside, start, end));
+        assert!(OverlapMode::ContainedWithin.includes(&inside, start, end));
+        assert!(!OverlapMode::ContainedWithin.includes(&inside, start, at(10, 30)));
+
+        let filter = EventFilter {
+            overlap: OverlapMode::StartsWithin,
+            ..Default::default()
+        };
+        assert!(filter.matches(&straddling));
+        assert!(!filter.matches_in_range(&straddling, start, end));
+    }
+
+    #[test]
+    fn test_overlap_mode_all_day() {
+        let day = |d, h| Local.with_ymd_and_hms(2024, 6, d, h, 0, 0).unwrap();
+        // Runs June 11-12, so it straddles a range starting on the 12th
+        let straddling = EventItem {
+            start_date: day(11, 0),
+            end_date: day(13, 0),
+            ..test_event("trip", (0, 0), (0, 0), true)
+        };
+        let today = EventItem {
+            start_date: day(12, 0),
+            end_date: day(13, 0),
+            ..test_event("holiday", (0, 0), (0, 0), true)
+        };
+        let (start, end) = (day(12, 9), day(14, 0));
+
+        assert!(OverlapMode::Overlaps.includes(&straddling, start, end));
+        assert!(!OverlapMode::StartsWithin.includes(&straddling, start, end));
+        assert!(!OverlapMode::ContainedWithin.includes(&straddling, start, end));
+
+        // Compared by date, so it starts within a range beginning at 09:00
+        assert!(OverlapMode::StartsWithin.includes(&today, start, end));
+        assert!(OverlapMode::ContainedWithin.includes(&today, start, end));
+        // A range ending at midnight on the 12th doesn't include that day
+        assert!(!OverlapMode::Overlaps.includes(&today, day(11, 9), day(12, 0)));
+    }
+
+    #[test]
+    fn test_group_agenda() {
+        let on = |d, (h, m)| Local.with_ymd_and_hms(2024, 6, d, h, m, 0).unwrap();
+        let event = |title, start, end, all_day| EventItem {
+            start_date: start,
+            end_date: end,
+            ..test_event(title, (0, 0), (0, 0), all_day)
+        };
+        let events = vec![
+            event("trip", on(10, (0, 0)), on(14, (0, 0)), true),
+            event("holiday", on(12, (0, 0)), on(13, (0, 0)), true),
+            event("standup", on(12, (9, 0)), on(12, (9, 15)), false),
+            event("late show", on(13, (1, 0)), on(13, (2, 0)), false),
+            event("lunch", on(13, (12, 0)), on(13, (13, 0)), false),
+        ];
+        let titles = |list: &[EventItem]| list.iter().map(|e| e.title.clone()).collect::<Vec<_>>();
+
+        // A day starting at 04:00 keeps the 01:00 show on the 12th, and the
+        // trip that began earlier is listed on the first day
+        let agenda = group_agenda(events.clone(), 4, NaiveDate::from_ymd_opt(2024, 6, 12));
+        assert_eq!(agenda.len(), 5);
+        let days: Vec<_> = agenda
+            .days
+            .iter()
+            .map(|d| (d.date.day(), titles(&d.all_day), titles(&d.timed)))
+            .collect();
+        assert_eq!(
+            days,
+            [
+                (
+                    12,
+                    vec!["trip".to_string(), "holiday".to_string()],
+                    vec!["standup".to_string(), "late show".to_string()]
+                ),
+                (13, vec![], vec!["lunch".to_string()]),
+            ]
+        );
+
+        // Without a day start or first day, events stay on their own dates
+        let agenda = Agenda::from_events(events);
+        let dates: Vec<_> = agenda.days.iter().map(|d| d.date.day()).collect();
+        assert_eq!(dates, [10, 12, 13]);
+    }
+
+    #[test]
+    fn test_check_availability() {
+        let busy_free =
+            EKCalendarEventAvailabilityMask::Busy | EKCalendarEventAvailabilityMask::Free;
+        assert_eq!(
+            check_availability("Work", busy_free, EventAvailability::Free).unwrap(),
+            EKEventAvailability::Free
+        );
+        assert!(matches!(
+            check_availability("Work", busy_free, EventAvailability::Tentative),
+            Err(EventKitError::AvailabilityUnsupported {
+                ref calendar,
+                availability: EventAvailability::Tentative,
+            }) if calendar == "Work"
+        ));
+        assert!(
+            check_availability(
+                "Any",
+                EKCalendarEventAvailabilityMask::all(),
+                EventAvailability::NotSupported
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_overdue_deadline() {
+        let midnight = Local.with_ymd_and_hms(2024, 6, 12, 0, 0, 0).unwrap();
+        let nine = Local.with_ymd_and_hms(2024, 6, 12, 9, 0, 0).unwrap();
+
+        assert_eq!(overdue_deadline(nine, true), nine);
+        // Due "today" with no time isn't overdue until the day is over
+        assert_eq!(
+            overdue_deadline(midnight, false),
+            Local.with_ymd_and_hms(2024, 6, 13, 0, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rrule_round_trip() {
+        let rule =
+            RecurrenceRule::from_rrule("RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=10;BYDAY=MO,-1FR")
+                .unwrap();
+        assert_eq!(rule.frequency, RecurrenceFrequency::Weekly);
+        assert_eq!(rule.interval, 2);
+        assert_eq!(rule.end, Some(RecurrenceEnd::Count(10)));
+        assert_eq!(
+            rule.days_of_week,
+            vec![
+                RecurrenceDay {
+                    weekday: Weekday::Mon,
+                    week: 0
+                },
+                RecurrenceDay {
+                    weekday: Weekday::Fri,
+                    week: -1
+                },
+            ]
+        );
+        assert_eq!(
+            rule.to_rrule(),
+            "RRULE:FREQ=WEEKLY;INTERVAL=2;COUNT=10;BYDAY=MO,-1FR"
+        );
+
+        let daily: RecurrenceRule = "FREQ=DAILY;UNTIL=20250301T120000Z;WKST=MO".parse().unwrap();
+        assert_eq!(daily.interval, 1);
+        assert_eq!(daily.to_rrule(), "RRULE:FREQ=DAILY;UNTIL=20250301T120000Z");
+
+        // A date-only UNTIL covers the whole day
+        let monthly = RecurrenceRule::from_rrule("FREQ=MONTHLY;UNTIL=20250301").unwrap();
+        let Some(RecurrenceEnd::Until(until)) = monthly.end else {
+            panic!("expected an UNTIL end");
+        };
+        assert_eq!(
+            until.date_naive(),
+            NaiveDate::from_ymd_opt(2025, 3, 1).unwrap()
+        );
+        assert_eq!((until.hour(), until.minute()), (23, 59));
+
+        for text in [
+            "RRULE:FREQ=MONTHLY;BYMONTHDAY=1,15,-1",
+            "RRULE:FREQ=MONTHLY;BYDAY=MO,TU,WE,TH,FR;BYSETPOS=-1",
+            "RRULE:FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3,10",
+            "RRULE:FREQ=YEARLY;BYYEARDAY=100;BYWEEKNO=20",
+        ] {
+            let rule = RecurrenceRule::from_rrule(text).unwrap();
+            assert!(rule.has_day_constraints());
+            assert_eq!(rule.to_rrule(), text);
+        }
+        let dst = RecurrenceRule::from_rrule("FREQ=YEARLY;BYDAY=-1SU;BYMONTH=3").unwrap();
+        assert_eq!(dst.months_of_year, [3]);
+        assert_eq!(dst.to_string(), "every year on the last Sun in March");
+    }
+
+    #[test]
+    fn test_rrule_parse_errors() {
+        for bad in [
+            "",
+            "INTERVAL=2",
+            "FREQ=HOURLY",
+            "FREQ=DAILY;INTERVAL=0",
+            "FREQ=DAILY;COUNT=3;UNTIL=20250301",
+            "FREQ=WEEKLY;BYDAY=XX",
+            "FREQ=WEEKLY;BYMONTHDAY=15",
+            "FREQ=MONTHLY;BYMONTHDAY=32",
+            "FREQ=MONTHLY;BYMONTH=3",
+            "FREQ=YEARLY;BYMONTH=-3",
+            "FREQ=MONTHLY;BYSETPOS=-1",
+            "FREQ=DAILY;BYSECOND=5",
+            "FREQ=DAILY;COUNT",
+        ] {
+            assert!(
+                matches!(
+                    RecurrenceRule::from_rrule(bad),
+                    Err(EventKitError::InvalidRRule(_))
+                ),
+                "{bad:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_format_os_version() {
+        assert_eq!(format_os_version((14, 4, 1)), "14.4.1");
+        assert_eq!(format_os_version((15, 0, 0)), "15.0");
+    }
+
+    #[test]
+    fn test_reminder_filter() {
+        let milk = ReminderItem {
+            calendar_title: Some("Shopping".to_string()),
+            ..test_reminder("Buy milk")
+        };
+        let call = ReminderItem {
+            notes: Some("About MILK prices".to_string()),
+            due_date: Some(Local.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap()),
+            completed: true,
+            ..test_reminder("Call Bob")
+        };
+
+        assert!(ReminderFilter::default().matches(&milk));
+        let text = ReminderFilter {
+            text: Some("milk".to_string()),
+            ..Default::default()
+        };
+        assert!(text.matches(&milk) && text.matches(&call));
+
+        let shopping = ReminderFilter {
+            calendar_titles: Some(vec!["Shopping".to_string()]),
+            ..text.clone()
+        };
+        assert!(shopping.matches(&milk) && !shopping.matches(&call));
+
+        let due = ReminderFilter {
+            due_before: Some(Local.with_ymd_and_hms(2025, 3, 2, 0, 0, 0).unwrap()),
+            ..Default::default()
+        };
+        assert!(!due.matches(&milk) && due.matches(&call));
+
+        let open = ReminderFilter {
+            completed: Some(false),
+            ..text
+        };
+        assert!(open.matches(&milk) && !open.matches(&call));
+    }
+
+    #[test]
+    fn test_reminder_query() {
+        let day = |d| Local.with_ymd_and_hms(2025, 3, d, 9, 0, 0).unwrap();
+        let report = ReminderItem {
+            calendar_title: Some("Work".to_string()),
+            due_date: Some(day(3)),
+            ..test_reminder("Send report")
+        };
+
+        let query = ReminderQuery::new()
+            .lists(["Work"])
+            .incomplete()
+            .search("REPORT");
+        assert!(query.matches(&report));
+        assert!(query.clone().due_between(day(1), day(4)).matches(&report));
+        assert!(!query.clone().due_between(day(4), day(5)).matches(&report));
+        assert!(!query.completed().matches(&report));
+        assert!(!ReminderQuery::new().lists(["Home"]).matches(&report));
+    }
+
+    #[test]
+    fn test_event_query() {
+        let at = |h, m| Local.with_ymd_and_hms(2024, 6, 12, h, m, 0).unwrap();
+        let mut review = test_event("Design review", (10, 0), (11, 0), false);
+        review.calendar_title = Some("Work".to_string());
+        review.location = Some("Room 4".to_string());
+
+        let query = EventQuery::new()
+            .between(at(9, 0), at(12, 0))
+            .calendars(["Work"])
+            .search("ROOM");
+        assert!(query.matches(&review));
+        assert!(!query.clone().between(at(12, 0), at(13, 0)).matches(&review));
+        assert!(!query.clone().calendars(["Home"]).matches(&review));
+        assert!(!query.clone().search("lunch").matches(&review));
+
+        let holiday = test_event("Holiday", (0, 0), (0, 0), true);
+        assert!(EventQuery::new().matches(&holiday));
+        assert!(!EventQuery::new().exclude_all_day().matches(&holiday));
+    }
+
+    #[test]
+    fn test_source_matches() {
+        let icloud = ("iCloud", EKSourceType::CalDAV);
+        let google = ("Google", EKSourceType::CalDAV);
+        let local = ("On My Mac", EKSourceType::Local);
+        let matches = |wanted: &CalendarSource, (title, kind): (&str, EKSourceType)| {
+            source_matches(wanted, title, kind)
+        };
+
+        assert!(matches(&CalendarSource::ICloud, icloud));
+        assert!(!matches(&CalendarSource::ICloud, google));
+        assert!(matches(&CalendarSource::Local, local));
+        assert!(!matches(&CalendarSource::Local, icloud));
+        assert!(matches(
+            &CalendarSource::Named("google".to_string()),
+            google
+        ));
+        assert!(!matches(&CalendarSource::Default, icloud));
+    }
+
+    #[test]
+    fn test_check_entity_type() {
+        assert!(check_entity_type("Work", EKEntityMask::Event, EntityType::Event).is_ok());
+        assert!(
+            check_entity_type(
+                "Shared",
+                EKEntityMask::Event | EKEntityMask::Reminder,
+                EntityType::Reminder
+            )
+            .is_ok()
+        );
+
+        let err = check_entity_type("Work", EKEntityMask::Event, EntityType::Reminder).unwrap_err();
+        assert!(matches!(
+            err,
+            EventKitError::CalendarWrongType { ref title, expected: EntityType::Reminder }
+                if title == "Work"
+        ));
+        assert_eq!(err.to_string(), "Calendar 'Work' can't hold reminders");
+
+        assert!(matches!(
+            check_entity_type("Groceries", EKEntityMask::Reminder, EntityType::Event),
+            Err(EventKitError::CalendarWrongType {
+                expected: EntityType::Event,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_calendar_color() {
+        let color = color_from_components(1.0, 0.5, 0.0);
+        assert_eq!(
+            color,
+            CalendarColor {
+                red: 255,
+                green: 128,
+                blue: 0
+            }
+        );
+        assert_eq!(color.to_string(), "#FF8000");
+        assert_eq!(color_from_components(-0.2, 1.7, 0.0).to_string(), "#00FF00");
+    }
+
+    #[test]
+    fn test_check_priority() {
+        for value in 0..=9 {
+            assert!(check_priority(Priority::from(value)).is_ok());
+        }
+        assert!(matches!(
+            check_priority(Priority::Raw(10)),
+            Err(EventKitError::InvalidPriority(10))
+        ));
+    }
+
+    #[test]
+    fn test_priority_conversions() {
+        for value in 0..=u8::MAX {
+            assert_eq!(Priority::from(value).value(), value);
+        }
+        assert!(matches!(Priority::from(5), Priority::Medium));
+        assert!(matches!(Priority::from(3), Priority::Raw(3)));
+        assert_eq!(Priority::Raw(9), Priority::Low);
+        assert_eq!(Priority::Raw(3).level(), Priority::High);
+        assert_eq!(Priority::Raw(7).level(), Priority::Low);
+    }
+
+    #[test]
+    fn test_mock_store_round_trip() {
+        let store = MockStore::new();
+        let first = store
+            .create_reminder("Buy milk", None, None, Some(Priority::High), None)
+            .unwrap();
+        store
+            .create_reminder("Call Bob", Some("About lunch"), Some("Work"), None, None)
+            .unwrap();
+        assert_eq!(first.identifier, "mock-1");
+        assert_eq!(first.calendar_title.as_deref(), Some("Reminders"));
+
+        assert_eq!(store.fetch_reminders(None).unwrap().len(), 2);
+        let work = store.fetch_reminders(Some(&["Work"])).unwrap();
+        assert_eq!(work.len(), 1);
+        assert_eq!(work[0].title, "Call Bob");
+
+        let updated = store
+            .update_reminder(&"mock-1".into(), None, None, Some(true), None, None)
+            .unwrap();
+        assert!(updated.completed);
+        assert_eq!(updated.priority, Priority::High);
+        assert!(matches!(
+            store.update_reminder(
+                &"mock-1".into(),
+                None,
+                None,
+                None,
+                Some(Priority::Raw(10)),
+                None
+            ),
+            Err(EventKitError::InvalidPriority(10))
+        ));
+
+        let due = Local.with_ymd_and_hms(2025, 3, 1, 9, 0, 0).unwrap();
+        let dated = store
+            .update_reminder(&"mock-1".into(), None, None, None, None, Some(Some(due)))
+            .unwrap();
+        assert_eq!(dated.due_date, Some(due));
+        let cleared = store
+            .update_reminder(&"mock-1".into(), None, None, None, None, Some(None))
+            .unwrap();
+        assert_eq!(cleared.due_date, None);
+
+        store.delete_reminder(&"mock-1".into()).unwrap();
+        assert!(matches!(
+            store.get_reminder(&"mock-1".into()),
+            Err(EventKitError::ItemNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_sort_reminders() {
+        let mut reminders: Vec<_> = [("b", 5), ("A", 0), ("c", 1)]
+            .into_iter()
+            .map(|(title, priority)| ReminderItem {
+                priority: Priority::from(priority),
+                ..test_reminder(title)
+            })
+            .collect();
+        let due = Local.with_ymd_and_hms(2025, 6, 1, 9, 0, 0).unwrap();
+        reminders[0].due_date = Some(due + Duration::days(1));
+        reminders[1].due_date = Some(due);
+        reminders[1].completed = true;
+        let titles = |r: &[ReminderItem]| r.iter().map(|r| r.title.clone()).collect::<Vec<_>>();
+
+        sort_reminders_default(&mut reminders);
+        assert_eq!(titles(&reminders), ["b", "c", "A"]);
+
+        SortOrder::StartAsc.sort_reminders(&mut reminders);
+        assert_eq!(titles(&reminders), ["A", "b", "c"]);
+        SortOrder::StartDesc.sort_reminders(&mut reminders);
+        assert_eq!(titles(&reminders), ["b", "A", "c"]);
+        SortOrder::TitleAsc.sort_reminders(&mut reminders);
+        assert_eq!(titles(&reminders), ["A", "b", "c"]);
+        SortOrder::PriorityThenDue.sort_reminders(&mut reminders);
+        assert_eq!(titles(&reminders), ["c", "b", "A"]);
+
+        for (index, days) in [(0, 2), (1, 0), (2, 1)] {
+            reminders[index].creation_date = Some(Local::now() - Duration::days(days));
+        }
+        SortOrder::CreatedDesc.sort_reminders(&mut reminders);
+        assert_eq!(titles(&reminders), ["b", "A", "c"]);
+
+        let mut limited = reminders.clone();
+        SortOrder::TitleAsc.sort_reminders_limited(&mut limited, 2);
+        assert_eq!(titles(&limited), ["A", "b"]);
+        let mut limited = reminders.clone();
+        SortOrder::StartDesc.sort_reminders_limited(&mut limited, 1);
+        assert_eq!(titles(&limited), ["b"]);
+        SortOrder::None.sort_reminders_limited(&mut reminders, 5);
+        assert_eq!(reminders.len(), 3);
+    }
+
+    #[test]
+    fn test_sort_events() {
+        let mut events = vec![
+            test_event("b", (9, 0), (10, 0), false),
+            test_event("C", (8, 0), (9, 0), false),
+            test_event("a", (11, 0), (12, 0), false),
+        ];
+        let titles = |e: &[EventItem]| e.iter().map(|e| e.title.clone()).collect::<Vec<_>>();
+
+        SortOrder::default().sort_events(&mut events);
+        assert_eq!(titles(&events), ["C", "b", "a"]);
+        SortOrder::StartDesc.sort_events(&mut events);
+        assert_eq!(titles(&events), ["a", "b", "C"]);
+        SortOrder::TitleAsc.sort_events(&mut events);
+        assert_eq!(titles(&events), ["a", "b", "C"]);
+
+        events[0].creation_date = Some(Local::now() - Duration::days(1));
+        events[2].creation_date = Some(Local::now());
+        events[1].last_modified_date = Some(Local::now());
+        SortOrder::CreatedDesc.sort_events(&mut events);
+        assert_eq!(titles(&events), ["C", "a", "b"]);
+        SortOrder::ModifiedDesc.sort_events(&mut events);
+        assert_eq!(titles(&events)[0], "b");
+    }
+
+    #[test]
+    fn test_check_event_range() {
+        let start = Local.with_ymd_and_hms(2024, 6, 12, 9, 0, 0).unwrap();
+        assert!(check_event_range(start, start + Duration::minutes(1), false).is_ok());
+        assert!(matches!(
+            check_event_range(start, start, false),
+            Err(EventKitError::InvalidDateRange)
+        ));
+        assert!(check_event_range(start, start - Duration::hours(1), false).is_err());
+
+        assert!(check_event_range(start, start, true).is_ok());
+        assert!(check_event_range(start, start + Duration::days(1), true).is_ok());
+        assert!(check_event_range(start, start - Duration::days(1), true).is_err());
+    }
+
+    #[test]
+    fn test_with_retry() {
+        let policy = RetryPolicy {
+            max_retries: 2,
+            initial_backoff: std::time::Duration::ZERO,
+        };
+        let is_transient = |e: &&str| *e == "busy";
+
+        // Succeeds on the last allowed attempt
+        let mut attempts = 0;
+        let result = with_retry(&policy, is_transient, || {
+            attempts += 1;
+            if attempts < 3 {
+                Err("busy")
+            } else {
+                Ok(attempts)
+            }
+        });
+        assert_eq!(result, Ok(3));
+
+        // Gives up once the retries are used up
+        let mut attempts = 0;
+        let result: std::result::Result<(), _> = with_retry(&policy, is_transient, || {
+            attempts += 1;
+            Err("busy")
+        });
+        assert_eq!((result, attempts), (Err("busy"), 3));
+
+        // Fails immediately on a permanent error
+        let mut attempts = 0;
+        let result: std::result::Result<(), _> = with_retry(&policy, is_transient, || {
+            attempts += 1;
+            Err("denied")
+        });
+        assert_eq!((result, attempts), (Err("denied"), 1));
+
+        // The default policy never retries
+        let mut attempts = 0;
+        let _ = with_retry(&RetryPolicy::default(), is_transient, || {
+            attempts += 1;
+            Err::<(), _>("busy")
+        });
+        assert_eq!(attempts, 1);
+    }
+
+    #[test]
+    fn test_check_reminder_alarms() {
+        let relative = Alarm::Relative(Duration::minutes(-15));
+        let absolute = Alarm::Absolute(Local.with_ymd_and_hms(2024, 6, 12, 9, 0, 0).unwrap());
+
+        assert!(check_reminder_alarms(&[], false, "r").is_ok());
+        assert!(check_reminder_alarms(std::slice::from_ref(&absolute), false, "r").is_ok());
+        assert!(check_reminder_alarms(&[relative.clone(), absolute], true, "r").is_ok());
+        assert!(matches!(
+            check_reminder_alarms(&[relative], false, "r"),
+            Err(EventKitError::NoDueDate(name)) if name == "r"
+        ));
+
+        let office = StructuredLocation {
+            title: "Office".to_string(),
+            latitude: 37.3349,
+            longitude: -122.009,
+            radius: 100.0,
+        };
+        assert!(check_reminder_alarms(&[Alarm::on_arrival(office.clone())], false, "r").is_ok());
+        for bad in [
+            StructuredLocation {
+                latitude: 91.0,
+                ..office.clone()
+            },
+            StructuredLocation {
+                longitude: -180.5,
+                ..office.clone()
+            },
+            StructuredLocation {
+                radius: -1.0,
+                ..office.clone()
+            },
+            StructuredLocation {
+                latitude: f64::NAN,
+                ..office.clone()
+            },
+        ] {
+            assert!(matches!(
+                check_reminder_alarms(&[Alarm::on_departure(bad)], true, "r"),
+                Err(EventKitError::InvalidLocation(title)) if title == "Office"
+            ));
+        }
+    }
+
+    #[test]
+    fn test_access_result() {
+        let access = |granted, status| AccessResult { granted, status };
+        assert!(
+            access(true, AuthorizationStatus::FullAccess)
+                .into_result()
+                .is_ok()
+        );
+        assert!(matches!(
+            access(false, AuthorizationStatus::Denied).into_result(),
+            Err(EventKitError::AuthorizationDenied)
+        ));
+        assert!(matches!(
+            access(false, AuthorizationStatus::Restricted).into_result(),
+            Err(EventKitError::AuthorizationRestricted)
+        ));
+    }
+
+    #[test]
+    #[ignore = "needs calendar access on macOS"]
+    fn test_update_event_all_day_round_trip() {
+        let manager = EventsManager::new();
+        let start = Local::now() + Duration::days(30);
+        let start = start.with_nanosecond(0).unwrap();
+        let end = start + Duration::hours(1);
+        let event = manager
+            .create_event(
+                "eventkit-rs all-day test",
+                start,
+                end,
+                None,
+                None,
+                None,
+                false,
+                None,
+                &[],
+                None,
+                &[],
+                None,
+                None,
+            )
+            .unwrap();
+
+        let on = manager.update_event(
+            &event.identifier,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(true),
+            None,
+            None,
+            Span::ThisEvent,
+        );
+        let off = manager.update_event(
+            &event.identifier,
+            None,
+            None,
+            None,
+            None,
+            None,
+            None,
+            Some(false),
+            None,
+            None,
+            Span::ThisEvent,
+        );
+        manager
+            .delete_event(&event.identifier, Span::ThisEvent)
+            .unwrap();
+
+        assert!(on.unwrap().all_day);
+        let off = off.unwrap();
+        assert!(!off.all_day);
+        assert_eq!((off.start_date, off.end_date), (start, end));
+    }
+
+    #[test]
+    #[ignore = "needs reminders access on macOS"]
+    fn test_skip_reminder_occurrence_count_round_trip() {
+        let manager = RemindersManager::new();
+        let due = Local::now() + Duration::days(30);
+        let due = due.with_nanosecond(0).unwrap();
+        let reminder = manager
+            .create_reminder(
+                "eventkit-rs skip test",
+                None,
+                None,
+                None,
+                &[],
+                Some(due),
+                None,
+            )
+            .unwrap();
+
+        let rule = RecurrenceRule {
+            end: Some(RecurrenceEnd::Count(3)),
+            ..RecurrenceRule::new(RecurrenceFrequency::Daily, 1)
+        };
+        let ek_reminder = manager.find_reminder_by_id(&reminder.identifier).unwrap();
+        let rules = NSArray::from_retained_slice(&[recurrence_to_ek(&rule)]);
+        unsafe { ek_reminder.setRecurrenceRules(Some(&rules)) };
+        manager.finish_reminder(&ek_reminder, true).unwrap();
+
+        let skipped = manager.skip_reminder_occurrence(&reminder.identifier);
+        let refetched = manager.get_reminder(&reminder.identifier);
+        manager.delete_reminder(&reminder.identifier).unwrap();
+
+        assert_eq!(skipped.unwrap().due_date, Some(due + Duration::days(1)));
+        let refetched = refetched.unwrap();
+        assert_eq!(refetched.recurrence_rules.len(), 1);
+        assert_eq!(
+            refetched.recurrence_rules[0].end,
+            Some(RecurrenceEnd::Count(2))
+        );
+    }
+
+    #[test]
+    fn test_fuzzy_title_matches() {
+        let titles = ["Work", "work", "Workouts", "Home", "Groceries"];
+        assert_eq!(fuzzy_title_matches("WORK", &titles), [0, 1]);
+        assert_eq!(fuzzy_title_matches("home", &titles), [3]);
+        assert_eq!(fuzzy_title_matches("gro", &titles), [4]);
+        assert_eq!(fuzzy_title_matches("wor", &titles), [0, 1, 2]);
+        assert!(fuzzy_title_matches("school", &titles).is_empty());
+    }
+
+    #[test]
+    fn test_event_duration() {
+        let meeting = test_event("meeting", (9, 0), (10, 30), false);
+        assert_eq!(meeting.duration(), Duration::minutes(90));
+        assert_eq!(meeting.day_count(), 1);
+
+        let at = |h, m| Local.with_ymd_and_hms(2024, 6, 12, h, m, 0).unwrap();
+        assert!(meeting.is_future(at(8, 59)));
+        assert!(meeting.is_ongoing(at(9, 0)));
+        assert!(!meeting.is_ongoing(at(10, 30)));
+        assert!(meeting.is_past(at(10, 30)));
+
+        // A zero-length event is never ongoing, and is past once it starts
+        let marker = test_event("marker", (12, 0), (12, 0), false);
+        assert_eq!(marker.duration(), Duration::zero());
+        assert_eq!(marker.day_count(), 1);
+        assert!(!marker.is_ongoing(at(12, 0)));
+        assert!(marker.is_past(at(12, 0)));
+        assert!(!marker.is_future(at(12, 0)));
+    }
+
+    #[test]
+    fn test_all_day_duration() {
+        // US DST starts on March 10, 2024, so in a US zone this day is 23
+        // hours long; it still counts as one day
+        let day = |d| Local.with_ymd_and_hms(2024, 3, d, 0, 0, 0).unwrap();
+        let mut event = test_event("holiday", (0, 0), (0, 0), true);
+        event.start_date = day(10);
+        event.end_date = day(11);
+        assert_eq!(event.day_count(), 1);
+        assert_eq!(event.duration(), Duration::days(1));
+
+        // EventKit's style of end: the last second of the final day
+        event.end_date = Local.with_ymd_and_hms(2024, 3, 12, 23, 59, 59).unwrap();
+        assert_eq!(event.day_count(), 3);
+        assert_eq!(event.duration(), Duration::days(3));
+    }
+
+    #[test]
+    fn test_group_by_calendar() {
+        let calendar = |id: &str| CalendarInfo {
+            identifier: id.into(),
+            title: "Work".to_string(),
+            source: None,
+            allows_modifications: true,
+            calendar_type: CalendarType::Local,
+            color: None,
+        };
+        let in_calendar = |title, start, end, id: &str| EventItem {
+            calendar_identifier: Some(id.into()),
+            ..test_event(title, start, end, false)
+        };
+        let calendars = vec![calendar("a"), calendar("b"), calendar("c")];
+        let events = vec![
+            in_calendar("standup", (9, 0), (9, 15), "b"),
+            in_calendar("review", (10, 0), (11, 0), "a"),
+            in_calendar("lunch", (12, 0), (13, 0), "b"),
+        ];
+
+        let groups = group_by_calendar(calendars.clone(), events.clone(), false);
+        let summary: Vec<(&str, Vec<&str>)> = groups
+            .iter()
+            .map(|(cal, events)| {
+                let titles = events.iter().map(|e| e.title.as_str()).collect();
+                (cal.identifier.as_str(), titles)
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [("a", vec!["review"]), ("b", vec!["standup", "lunch"])]
+        );
+
+        let groups = group_by_calendar(calendars, events, true);
+        assert_eq!(groups.len(), 3);
+        assert!(groups[2].1.is_empty());
+    }
+
+    #[test]
+    fn test_wait_for_completion() {
+        let timeout = std::time::Duration::from_millis(10);
+
+        let slot = Arc::new((Mutex::new(None::<u32>), Condvar::new()));
+        let filler = Arc::clone(&slot);
+        std::thread::spawn(move || {
+            let (lock, cvar) = &*filler;
+            *lock.lock().unwrap() = Some(7);
+            cvar.notify_one();
+        })
+        .join()
+        .unwrap();
+        assert_eq!(wait_for_completion(&slot, timeout).unwrap(), 7);
+
+        // A handler that never fires times out instead of hanging
+        let slot = (Mutex::new(None::<u32>), Condvar::new());
+        assert!(matches!(
+            wait_for_completion(&slot, timeout),
+            Err(EventKitError::Timeout(t)) if t == timeout
+        ));
+    }
+
+    #[test]
+    fn test_predicate_chunks() {
+        let start = Local.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap();
+        let short = start + Duration::days(30);
+        assert_eq!(predicate_chunks(start, short), [(start, short)]);
+
+        let end = Local.with_ymd_and_hms(2030, 1, 1, 0, 0, 0).unwrap();
+        let chunks = predicate_chunks(start, end);
+        assert_eq!(chunks.len(), 3);
+        assert_eq!(chunks[0].0, start);
+        assert_eq!(chunks[2].1, end);
+        for pair in chunks.windows(2) {
+            assert_eq!(pair[0].1, pair[1].0);
+        }
+        assert!(
+            chunks
+                .iter()
+                .all(|(s, e)| *e - *s <= Duration::days(PREDICATE_MAX_DAYS))
+        );
+    }
+
+    #[test]
+    fn test_dedup_by_key() {
+        let mut ids = vec!["a", "b", "a", "c", "b"];
+        dedup_by_key(&mut ids, |id| *id);
+        assert_eq!(ids, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_index_by_id() {
+        let (by_id, order) = index_by_id([
+            ("work".to_string(), "Work"),
+            ("home".to_string(), "Home"),
+            ("work".to_string(), "Work (again)"),
+        ]);
+        assert_eq!(order, ["work", "home"]);
+        assert_eq!(by_id.len(), 2);
+        assert_eq!(by_id["work"], "Work");
+    }
+
+    #[test]
+    fn test_next_occurrence() {
+        let due = Local.with_ymd_and_hms(2025, 1, 31, 9, 0, 0).unwrap();
+        let rule = |frequency, interval, end| RecurrenceRule {
+            end,
+            ..RecurrenceRule::new(frequency, interval)
+        };
+
+        let every_other_week = rule(RecurrenceFrequency::Weekly, 2, None);
+        assert_eq!(
+            next_occurrence(due, &every_other_week),
+            Local.with_ymd_and_hms(2025, 2, 14, 9, 0, 0).single()
+        );
+        assert_eq!(every_other_week.to_string(), "every 2 weeks");
+
+        // Months without the day clamp to their last day
+        let monthly = rule(RecurrenceFrequency::Monthly, 1, None);
+        assert_eq!(
+            next_occurrence(due, &monthly),
+            Local.with_ymd_and_hms(2025, 2, 28, 9, 0, 0).single()
+        );
+
+        // Nothing follows the last occurrence
+        let last = rule(RecurrenceFrequency::Daily, 1, Some(RecurrenceEnd::Count(1)));
+        assert_eq!(next_occurrence(due, &last), None);
+        let until = rule(
+            RecurrenceFrequency::Yearly,
+            1,
+            Some(RecurrenceEnd::Until(due)),
+        );
+        assert_eq!(next_occurrence(due, &until), None);
+        assert_eq!(until.to_string(), "every year, until 2025-01-31");
+    }
+
+    #[test]
+    fn test_recurrence_summary() {
+        let single = test_event("Single", (9, 0), (10, 0), false);
+        assert_eq!(single.recurrence_summary(), None);
+
+        let mut standup = RecurrenceRule::new(RecurrenceFrequency::Weekly, 2);
+        standup.days_of_week = vec![
+            RecurrenceDay {
+                weekday: Weekday::Mon,
+                week: 0,
+            },
+            RecurrenceDay {
+                weekday: Weekday::Wed,
+                week: 0,
+            },
+        ];
+        let mut review = RecurrenceRule::new(RecurrenceFrequency::Monthly, 1);
+        review.days_of_week = vec![RecurrenceDay {
+            weekday: Weekday::Fri,
+            week: -1,
+        }];
+        let repeating = EventItem {
+            has_recurrence: true,
+            recurrence_rules: vec![standup, review],
+            ..single.clone()
+        };
+        assert_eq!(
+            repeating.recurrence_summary().as_deref(),
+            Some("every 2 weeks on Mon, Wed; every month on the last Fri")
+        );
+
+        assert_eq!(ordinal(1), "1st");
+        assert_eq!(ordinal(12), "12th");
+        assert_eq!(ordinal(23), "23rd");
+        assert_eq!(ordinal(-2), "2nd-to-last");
+    }
+
+    #[test]
+    fn test_email_from_url() {
+        assert_eq!(
+            email_from_url("mailto:jane@example.com"),
+            Some("jane@example.com".to_string())
+        );
+        assert_eq!(
+            email_from_url("MAILTO:jane@example.com?subject=hi"),
+            Some("jane@example.com".to_string())
+        );
+        assert_eq!(email_from_url("mailto:"), None);
+        assert_eq!(email_from_url("https://example.com"), None);
+    }
+
+    #[test]
+    fn test_paginate() {
+        let items: Vec<u32> = (1..=5).collect();
+
+        assert_eq!(paginate(items.clone(), 0, None), vec![1, 2, 3, 4, 5]);
+        assert_eq!(paginate(items.clone(), 0, Some(2)), vec![1, 2]);
+        assert_eq!(paginate(items.clone(), 2, Some(2)), vec![3, 4]);
+        assert_eq!(paginate(items.clone(), 4, Some(2)), vec![5]);
+        assert!(paginate(items, 10, None).is_empty());
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let timed = test_event("Timed", (9, 0), (10, 0), false);
+        let all_day = test_event("All day", (0, 0), (23, 59), true);
+        let canceled = EventItem {
+            status: EventStatus::Canceled,
+            ..test_event("Canceled", (9, 0), (10, 0), false)
+        };
+        let free = EventItem {
+            availability: EventAvailability::Free,
+            ..test_event("Free", (9, 0), (10, 0), false)
+        };
+        let declined = EventItem {
+            my_participation_status: Some(ParticipantStatus::Declined),
+            ..test_event("Declined", (9, 0), (10, 0), false)
+        };
+
+        let keep_all = EventFilter::default();
+        assert!(
+            [&timed, &all_day, &canceled, &free, &declined]
+                .iter()
+                .all(|e| keep_all.matches(e))
+        );
+
+        let no_all_day = EventFilter {
+            exclude_all_day: true,
+            ..Default::default()
+        };
+        assert!(no_all_day.matches(&timed));
+        assert!(!no_all_day.matches(&all_day));
+
+        let no_canceled = EventFilter {
+            exclude_canceled: true,
+            ..Default::default()
+        };
+        assert!(no_canceled.matches(&timed));
+        assert!(!no_canceled.matches(&canceled));
+
+        let busy = EventFilter {
+            only_busy: true,
+            ..Default::default()
+        };
+        assert!(busy.matches(&timed));
+        assert!(!busy.matches(&free));
+
+        let no_declined = EventFilter {
+            exclude_declined: true,
+            ..Default::default()
+        };
+        assert!(no_declined.matches(&timed));
+        assert!(!no_declined.matches(&declined));
+    }
+
+    #[test]
+    fn test_all_day_dates() {
+        let timed = test_event("Timed", (9, 0), (10, 0), false);
+        assert_eq!(timed.all_day_dates(), None);
+
+        let day = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+        let single = test_event("Single", (0, 0), (23, 59), true);
+        assert_eq!(single.all_day_dates(), Some((day, day)));
+
+        let trip = EventItem {
+            end_date: single.start_date + Duration::days(3),
+            ..single.clone()
+        };
+        let last = NaiveDate::from_ymd_opt(2024, 6, 14).unwrap();
+        assert_eq!(trip.all_day_dates(), Some((day, last)));
+    }
+
+    #[test]
+    fn test_event_occurrence_handle() {
+        let single = test_event("Single", (9, 0), (10, 0), false);
+        assert_eq!(single.occurrence(), None);
+
+        let repeating = EventItem {
+            occurrence_date: Some(Local.with_ymd_and_hms(2024, 7, 3, 9, 0, 0).unwrap()),
+            ..test_event("Weekly", (9, 0), (10, 0), false)
+        };
+        assert_eq!(
+            repeating.occurrence(),
+            Some(EventOccurrence {
+                identifier: "Weekly".into(),
+                occurrence_date: Local.with_ymd_and_hms(2024, 7, 3, 9, 0, 0).unwrap(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_event_zone_times() {
+        let floating = test_event("Floating", (9, 0), (10, 0), false);
+        assert_eq!(
+            floating.start_in_event_zone(),
+            floating.start_date.fixed_offset()
+        );
+
+        let tokyo = EventItem {
+            time_zone: Some("Asia/Tokyo".to_string()),
+            ..test_event("Landing", (9, 0), (10, 0), false)
+        };
+        let start = tokyo.start_in_event_zone();
+        assert_eq!(start, tokyo.start_date);
+        assert_eq!(start.offset().local_minus_utc(), 9 * 3600);
+
+        let unknown = EventItem {
+            time_zone: Some("Not/AZone".to_string()),
+            ..test_event("Unknown", (9, 0), (10, 0), false)
+        };
+        assert_eq!(unknown.end_in_event_zone(), unknown.end_date.fixed_offset());
+    }
+
+    #[test]
+    fn test_missing_title_is_empty() {
+        assert_eq!(title_or_empty(None), "");
+        assert_eq!(
+            title_or_empty(Some(NSString::from_str("Standup"))),
+            "Standup"
+        );
+
+        let event = test_event("", (9, 0), (10, 0), false);
+        assert!(event.title.is_empty());
+        assert_eq!(conflicting_pairs(&[event.clone(), event], false).len(), 1);
+    }
+
+    #[test]
+    fn test_day_bounds() {
+        let date = NaiveDate::from_ymd_opt(2024, 6, 12).unwrap();
+
+        assert_eq!(
+            day_bounds(date, &Utc, 0),
+            Some((
+                Utc.with_ymd_and_hms(2024, 6, 12, 0, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 6, 12, 23, 59, 59).unwrap(),
+            ))
+        );
+        assert_eq!(
+            day_bounds(date, &Utc, 4),
+            Some((
+                Utc.with_ymd_and_hms(2024, 6, 12, 4, 0, 0).unwrap(),
+                Utc.with_ymd_and_hms(2024, 6, 13, 3, 59, 59).unwrap(),
+            ))
+        );
+    }
+
+    #[test]
+    fn test_day_bounds_across_dst() {
+        use chrono_tz::America::New_York;
+
+        // 02:00 doesn't exist on 2024-03-10 in New York, so the day starts at 03:00
+        let date = NaiveDate::from_ymd_opt(2024, 3, 10).unwrap();
+        let (start, end) = day_bounds(date, &New_York, 2).unwrap();
+        assert_eq!(
+            start,
+            New_York.with_ymd_and_hms(2024, 3, 10, 3, 0, 0).unwrap()
+        );
+        assert_eq!(
+            end,
+            New_York.with_ymd_and_hms(2024, 3, 11, 1, 59, 59).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_absolute_formats() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 12, 9, 15, 0).unwrap();
+
+        assert_eq!(
+            parse_datetime_from("2024-07-04 14:30", now),
+            Some(Utc.with_ymd_and_hms(2024, 7, 4, 14, 30, 0).unwrap())
+        );
+        assert_eq!(
+            parse_datetime_from("2024-07-04", now),
+            Some(Utc.with_ymd_and_hms(2024, 7, 4, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_datetime_from("07/04/2024 14:30", now),
+            Some(Utc.with_ymd_and_hms(2024, 7, 4, 14, 30, 0).unwrap())
+        );
+        assert_eq!(
+            parse_datetime_from("07/04/2024", now),
+            Some(Utc.with_ymd_and_hms(2024, 7, 4, 0, 0, 0).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_parse_datetime_keywords() {
+        // A Wednesday
+        let now = Utc.with_ymd_and_hms(2024, 6, 12, 9, 15, 0).unwrap();
+
+        assert_eq!(
+            parse_datetime_from("today", now),
+            Some(Utc.with_ymd_and_hms(2024, 6, 12, 0, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_datetime_from("Tomorrow 12:00", now),
+            Some(Utc.with_ymd_and_hms(2024, 6, 13, 12, 0, 0).unwrap())
+        );
+        assert_eq!(
+            parse_datetime_from("friday 08:00", now),
+            Some(Utc.with_ymd_and_hms(2024, 6, 14, 8, 0, 0).unwrap())
+        );
+        // The same weekday means next week, not today
+        assert_eq!(
+            parse_datetime_from("wed", now),
+            Some(Utc.with_ymd_and_hms(2024, 6, 19, 0, 0, 0).unwrap())
+        );
+        assert_eq!(parse_datetime_from("someday", now), None);
+        assert_eq!(parse_datetime_from("today 25:00", now), None);
+    }
+
+    #[test]
+    fn test_parse_datetime_relative_offsets() {
+        let now = Utc.with_ymd_and_hms(2024, 6, 12, 9, 15, 0).unwrap();
+
+        assert_eq!(
+            parse_datetime_from("+30m", now),
+            Some(now + Duration::minutes(30))
+        );
+        assert_eq!(
+            parse_datetime_from("+2h", now),
+            Some(now + Duration::hours(2))
+        );
+        assert_eq!(
+            parse_datetime_from("+1d", now),
+            Some(now + Duration::days(1))
+        );
+        assert_eq!(
+            parse_datetime_from("+1w", now),
+            Some(now + Duration::weeks(1))
+        );
+        assert_eq!(parse_datetime_from("+5y", now), None);
+        assert_eq!(parse_datetime_from("+h", now), None);
+    }
+
+    #[test]
+    fn test_parse_duration() {
+        assert_eq!(parse_duration("30m"), Some(Duration::minutes(30)));
+        assert_eq!(parse_duration("+2H"), Some(Duration::hours(2)));
+        assert_eq!(parse_duration(" 1d "), Some(Duration::days(1)));
+        assert_eq!(parse_duration("-1w"), Some(-Duration::weeks(1)));
+        assert_eq!(parse_duration("1y"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn test_parse_datetime_ambiguous_local_time() {
+        let tz = chrono_tz::America::New_York;
+        let now = tz.with_ymd_and_hms(2024, 11, 1, 9, 0, 0).unwrap();
+
+        // 01:30 happens twice when clocks fall back; the earlier (EDT) wins
+        let parsed = parse_datetime_from("2024-11-03 01:30", now).unwrap();
+        assert_eq!(parsed.naive_utc().to_string(), "2024-11-03 05:30:00");
+
+        // 02:30 doesn't exist when clocks spring forward
+        assert_eq!(parse_datetime_from("2024-03-10 02:30", now), None);
     }
 }
@@ -2,14 +2,41 @@
 //!
 //! A command-line interface for managing macOS Calendar events and Reminders.
 
-use chrono::{Duration, Local, NaiveDateTime, TimeZone};
+use chrono::{Duration, Local, NaiveTime};
+use clap::error::ErrorKind;
 use clap::{Parser, Subcommand};
-use eventkit::{AuthorizationStatus, EventKitError, EventsManager, RemindersManager};
+use eventkit::{
+    Agenda, Alarm, AuthorizationStatus, CalendarColor, CalendarId, CalendarSource,
+    EventAvailability, EventFilter, EventId, EventItem, EventKit, EventKitError, EventStatus,
+    EventsManager, NewReminder, Participant, Priority, Proximity, RecurrenceRule, ReminderFilter,
+    ReminderId, ReminderItem, RemindersManager, SortOrder, Span, StructuredLocation, WorkingHours,
+    parse_datetime, parse_duration,
+};
+use std::collections::{HashMap, HashSet};
+use std::io::IsTerminal;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::time::{Duration as StdDuration, Instant};
 
 #[derive(Parser)]
 #[command(name = "eventkit")]
 #[command(author, version, about = "Manage macOS Calendar and Reminders from the command line", long_about = None)]
+#[command(after_help = "Exit codes:
+  0  Success
+  1  Other error
+  2  Not authorized
+  3  Item or calendar not found
+  4  Invalid input or usage
+  5  Save or delete failed")]
 struct Cli {
+    /// Disable colored output (also honors the NO_COLOR environment variable)
+    #[arg(long, global = true)]
+    no_color: bool,
+
+    /// Check changes and show what would be saved, without saving anything
+    #[arg(long, global = true)]
+    dry_run: bool,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -30,6 +57,9 @@ enum Commands {
         #[arg(short, long)]
         events: bool,
     },
+
+    /// Check permissions and calendars, with hints for anything wrong
+    Doctor,
 }
 
 #[derive(Subcommand)]
@@ -38,7 +68,11 @@ enum RemindersCommands {
     Authorize,
 
     /// List all reminder lists (calendars)
-    Lists,
+    Lists {
+        /// Only show lists new reminders can be added to
+        #[arg(long)]
+        writable_only: bool,
+    },
 
     /// List reminders
     List {
@@ -54,9 +88,38 @@ enum RemindersCommands {
         #[arg(short, long)]
         completed: bool,
 
+        /// Show reminders completed since this date, most recent first
+        #[arg(long, value_name = "DATE")]
+        completed_since: Option<String>,
+
+        /// Show only incomplete reminders that are past due, most overdue first
+        #[arg(long)]
+        overdue: bool,
+
+        /// Show only incomplete reminders due by this date (overdue included)
+        #[arg(long, value_name = "DATE")]
+        due_before: Option<String>,
+
         /// Show all details
         #[arg(short, long)]
         all: bool,
+
+        /// Show at most N reminders
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Sync remote (iCloud/CalDAV) sources before listing
+        #[arg(long)]
+        refresh: bool,
+
+        /// Keep running and re-render whenever reminders change
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Sort by: start (due date), start-desc, title, priority, created, modified, none
+        /// [default: incomplete first, then due date and priority]
+        #[arg(long, value_parser = parse_sort_order)]
+        sort: Option<SortOrder>,
     },
 
     /// Create a new reminder
@@ -72,9 +135,23 @@ enum RemindersCommands {
         #[arg(short, long)]
         list: Option<String>,
 
-        /// Priority (0=none, 1-4=high, 5=medium, 6-9=low)
+        /// Priority: none, high, medium, low, or 0-9 (1-4 high, 5 medium, 6-9 low)
+        #[arg(short, long, value_parser = parse_priority)]
+        priority: Option<Priority>,
+
+        /// Link to attach (e.g. a ticket or email URL)
+        #[arg(short, long)]
+        url: Option<String>,
+    },
+
+    /// Create one reminder per line of a file (or stdin), in a single commit
+    Import {
+        /// File with one reminder title per line; reads stdin if omitted or "-"
+        file: Option<String>,
+
+        /// List to add the reminders to
         #[arg(short, long)]
-        priority: Option<usize>,
+        list: Option<String>,
     },
 
     /// Update an existing reminder
@@ -90,15 +167,61 @@ enum RemindersCommands {
         #[arg(short, long)]
         notes: Option<String>,
 
-        /// Priority (0=none, 1-4=high, 5=medium, 6-9=low)
+        /// Priority: none, high, medium, low, or 0-9 (1-4 high, 5 medium, 6-9 low)
+        #[arg(short, long, value_parser = parse_priority)]
+        priority: Option<Priority>,
+
+        /// New link; pass "" to remove it
         #[arg(short, long)]
-        priority: Option<usize>,
+        url: Option<String>,
     },
 
     /// Mark a reminder as complete
     Complete {
         /// Identifier of the reminder
         id: String,
+
+        /// When it was actually finished (e.g. "yesterday 17:00") [default: now]
+        #[arg(long)]
+        at: Option<String>,
+    },
+
+    /// Find reminders whose title or notes contain some text
+    Search {
+        /// Text to look for, ignoring case
+        query: String,
+
+        /// Only search these list(s)
+        #[arg(short, long)]
+        list: Option<Vec<String>>,
+
+        /// Only incomplete reminders
+        #[arg(short, long, conflicts_with = "completed")]
+        incomplete: bool,
+
+        /// Only completed reminders
+        #[arg(short, long)]
+        completed: bool,
+
+        /// Treat the query as a regular expression
+        #[cfg(feature = "regex")]
+        #[arg(short, long)]
+        regex: bool,
+    },
+
+    /// Complete every incomplete reminder matching the given filters
+    CompleteAll {
+        /// Only reminders in this list
+        #[arg(short, long)]
+        list: Option<String>,
+
+        /// Only reminders whose title or notes contain this text
+        #[arg(short, long)]
+        text: Option<String>,
+
+        /// Only reminders due before this date
+        #[arg(long, value_name = "DATE")]
+        due_before: Option<String>,
     },
 
     /// Mark a reminder as incomplete
@@ -107,16 +230,88 @@ enum RemindersCommands {
         id: String,
     },
 
-    /// Delete a reminder
+    /// Delete one or more reminders
     Delete {
-        /// Identifier of the reminder to delete
-        id: String,
+        /// Identifier(s) of the reminders to delete
+        ids: Vec<String>,
+
+        /// Delete all completed reminders
+        #[arg(long)]
+        completed: bool,
+
+        /// Delete reminders in this list (combine with --completed to narrow)
+        #[arg(short, long)]
+        list: Option<String>,
 
         /// Skip confirmation
         #[arg(short, long)]
         force: bool,
     },
 
+    /// Copy a reminder into a new, incomplete reminder
+    Duplicate {
+        /// Identifier of the reminder to copy
+        id: String,
+
+        /// List to put the copy in (default: the original's list)
+        #[arg(short, long)]
+        list: Option<String>,
+    },
+
+    /// Remind on arriving at (or leaving) a place
+    Geofence {
+        /// Identifier of the reminder
+        id: String,
+
+        /// Name of the place (e.g. "Office")
+        #[arg(short, long)]
+        title: String,
+
+        /// Latitude in degrees
+        #[arg(long, allow_hyphen_values = true)]
+        lat: f64,
+
+        /// Longitude in degrees
+        #[arg(long, allow_hyphen_values = true)]
+        lon: f64,
+
+        /// Radius in meters (0 uses the system default)
+        #[arg(short, long, default_value = "0")]
+        radius: f64,
+
+        /// Fire on leaving the place instead of arriving
+        #[arg(long)]
+        leave: bool,
+    },
+
+    /// Move a reminder to another list
+    Move {
+        /// Identifier of the reminder
+        id: String,
+
+        /// Target list, by title or identifier
+        list: String,
+    },
+
+    /// Push a reminder's due date back (e.g. by 1h or 1d)
+    Snooze {
+        /// Identifier of the reminder
+        id: String,
+
+        /// How long to snooze: 30m, 2h, 1d, 1w (negative moves it earlier)
+        #[arg(default_value = "1h", allow_hyphen_values = true)]
+        by: String,
+    },
+
+    /// Give a reminder a new due date
+    Reschedule {
+        /// Identifier of the reminder
+        id: String,
+
+        /// New due date/time (e.g. "tomorrow 09:00", "fri", "2024-12-20 14:00")
+        due: String,
+    },
+
     /// Show details of a specific reminder
     Show {
         /// Identifier of the reminder
@@ -130,7 +325,25 @@ enum EventsCommands {
     Authorize,
 
     /// List all calendars
-    Calendars,
+    Calendars {
+        /// Only show calendars new events can be added to
+        #[arg(long)]
+        writable_only: bool,
+    },
+
+    /// Create a new calendar
+    CreateCalendar {
+        /// Title of the calendar
+        title: String,
+
+        /// Account to create it in: default, icloud, local, or an account name
+        #[arg(short, long, value_parser = parse_calendar_source, default_value = "default")]
+        source: CalendarSource,
+
+        /// Color as #RRGGBB
+        #[arg(long, value_parser = parse_color)]
+        color: Option<CalendarColor>,
+    },
 
     /// List events
     List {
@@ -149,6 +362,34 @@ enum EventsCommands {
         /// Show all details
         #[arg(short, long)]
         all: bool,
+
+        /// Show at most N events
+        #[arg(long)]
+        limit: Option<usize>,
+
+        /// Sync remote (iCloud/CalDAV) sources before listing
+        #[arg(long)]
+        refresh: bool,
+
+        /// Hide all-day events
+        #[arg(long)]
+        no_all_day: bool,
+
+        /// Hide canceled events
+        #[arg(long)]
+        hide_canceled: bool,
+
+        /// Hide invitations you've declined
+        #[arg(long)]
+        hide_declined: bool,
+
+        /// Keep running and re-render whenever events change
+        #[arg(short, long)]
+        watch: bool,
+
+        /// Sort by: start, start-desc, title, created, modified, none
+        #[arg(long, value_parser = parse_sort_order, default_value = "start")]
+        sort: SortOrder,
     },
 
     /// Create a new event
@@ -156,11 +397,12 @@ enum EventsCommands {
         /// Title of the event
         title: String,
 
-        /// Start date/time (format: YYYY-MM-DD HH:MM or YYYY-MM-DD for all-day)
+        /// Start date/time (e.g. "2024-12-20 14:00", "tomorrow 09:30", "fri", "+2h";
+        /// a bare date for all-day)
         #[arg(short, long)]
         start: String,
 
-        /// End date/time (format: YYYY-MM-DD HH:MM or YYYY-MM-DD for all-day)
+        /// End date/time (same formats as --start)
         #[arg(short, long)]
         end: Option<String>,
 
@@ -183,65 +425,357 @@ enum EventsCommands {
         /// Create as all-day event
         #[arg(long)]
         all_day: bool,
+
+        /// Time zone the event belongs to (IANA name, e.g. "Asia/Tokyo");
+        /// --start and --end are still read as local times
+        #[arg(long)]
+        time_zone: Option<String>,
+
+        /// Make the event repeat, as an RFC 5545 rule such as
+        /// "FREQ=WEEKLY;BYDAY=MO,WE" (repeatable)
+        #[arg(long = "rrule", value_name = "RRULE")]
+        rrules: Vec<String>,
+
+        /// Add an alarm this long before the start, e.g. 15m or 1d (repeatable)
+        #[arg(long = "alarm", value_name = "DURATION")]
+        alarms: Vec<String>,
+
+        /// Show as: busy, free, tentative or unavailable [default: the calendar's]
+        #[arg(long, value_parser = parse_availability)]
+        show_as: Option<EventAvailability>,
+
+        /// Link to attach (e.g. a video-call URL)
+        #[arg(short, long)]
+        url: Option<String>,
     },
 
-    /// Delete an event
-    Delete {
-        /// Identifier of the event to delete
+    /// Add or remove an event's alarms (the whole series)
+    Alarm {
+        /// Identifier of the event
+        id: String,
+
+        /// Alarm this long before the start, e.g. 15m or 1d
+        #[arg(short, long, value_name = "DURATION", conflicts_with = "at")]
+        before: Option<String>,
+
+        /// Alarm at a fixed date and time
+        #[arg(short, long, value_name = "DATE")]
+        at: Option<String>,
+
+        /// Remove the given alarm instead of adding it
+        #[arg(short, long)]
+        remove: bool,
+
+        /// Remove every alarm
+        #[arg(long, conflicts_with_all = ["before", "at", "remove"])]
+        clear: bool,
+    },
+
+    /// Alert on arriving at or leaving a place (the whole series)
+    Geofence {
+        /// Identifier of the event
+        id: String,
+
+        /// Name of the place [default: the event's own location]
+        #[arg(short, long, requires_all = ["lat", "lon"])]
+        title: Option<String>,
+
+        /// Latitude in degrees [default: the event's own location]
+        #[arg(long, allow_hyphen_values = true, requires = "lon")]
+        lat: Option<f64>,
+
+        /// Longitude in degrees [default: the event's own location]
+        #[arg(long, allow_hyphen_values = true, requires = "lat")]
+        lon: Option<f64>,
+
+        /// Radius in meters [default: the location's own, or the system default]
+        #[arg(short, long)]
+        radius: Option<f64>,
+
+        /// Fire on leaving the place instead of arriving
+        #[arg(long)]
+        leave: bool,
+    },
+
+    /// Move an event earlier or later, keeping its length
+    Shift {
+        /// Identifier of the event
+        id: String,
+
+        /// How far to move it, e.g. 30m, 1d, or -1h for earlier
+        #[arg(allow_hyphen_values = true)]
+        by: String,
+    },
+
+    /// Make an event longer or shorter by moving its end
+    Extend {
+        /// Identifier of the event
+        id: String,
+
+        /// How much to add, e.g. 15m, or -15m to shorten it
+        #[arg(allow_hyphen_values = true)]
+        by: String,
+    },
+
+    /// Change how an event repeats (the whole series)
+    Repeat {
+        /// Identifier of the event
         id: String,
 
+        /// New RFC 5545 rule such as "FREQ=WEEKLY;BYDAY=MO,WE" (repeatable);
+        /// give none to stop the event repeating
+        #[arg(long = "rrule", value_name = "RRULE")]
+        rrules: Vec<String>,
+    },
+
+    /// Delete one or more events
+    Delete {
+        /// Identifier(s) of the events to delete
+        #[arg(required = true)]
+        ids: Vec<String>,
+
+        /// Delete every occurrence of recurring events, not just the first
+        #[arg(long)]
+        series: bool,
+
         /// Skip confirmation
         #[arg(short, long)]
         force: bool,
     },
 
+    /// Copy an event to a new start time
+    Duplicate {
+        /// Identifier of the event to copy
+        id: String,
+
+        /// Start of the copy (same formats as `add --start`); the duration is kept
+        #[arg(short, long)]
+        start: String,
+
+        /// Calendar to put the copy in (default: the original's calendar)
+        #[arg(short, long)]
+        calendar: Option<String>,
+    },
+
     /// Show details of a specific event
     Show {
+        /// Identifier or external (server-side) identifier of the event
+        id: String,
+    },
+
+    /// List the upcoming occurrences of a recurring event
+    Occurrences {
         /// Identifier of the event
         id: String,
+
+        /// Look at the next N days (default: 30)
+        #[arg(short, long, default_value = "30")]
+        days: i64,
+    },
+
+    /// Show when you're busy, merged into blocks without event details
+    Freebusy {
+        /// Look at the next N days (default: 7)
+        #[arg(short, long, default_value = "7")]
+        days: i64,
+
+        /// Only count specific calendar(s)
+        #[arg(short, long)]
+        calendar: Option<Vec<String>>,
+    },
+
+    /// Find events whose title, notes or location contain some text
+    Search {
+        /// Text to look for, ignoring case
+        query: String,
+
+        /// Look at the next N days (default: 365)
+        #[arg(short, long, default_value = "365")]
+        days: i64,
+
+        /// Also look at the past N days (default: 365)
+        #[arg(short, long, default_value = "365")]
+        past: i64,
+
+        /// Only search specific calendar(s)
+        #[arg(short, long)]
+        calendar: Option<Vec<String>>,
+    },
+
+    /// Find free slots between your busy events
+    Free {
+        /// Look at the next N days (default: 7)
+        #[arg(short, long, default_value = "7")]
+        days: i64,
+
+        /// Shortest slot to show, e.g. 30m or 2h (default: 30m)
+        #[arg(short, long, default_value = "30m")]
+        min: String,
+
+        /// Only look within these hours each day, e.g. 09:00-17:00
+        #[arg(long, value_parser = parse_working_hours)]
+        hours: Option<WorkingHours>,
+
+        /// Count Saturdays and Sundays as working days
+        #[arg(long, requires = "hours")]
+        weekends: bool,
+
+        /// Only count specific calendar(s)
+        #[arg(short, long)]
+        calendar: Option<Vec<String>>,
     },
 }
 
 fn main() {
-    let cli = Cli::parse();
+    // clap exits with 2 on usage errors, which would read as "not authorized"
+    let cli = match Cli::try_parse() {
+        Ok(cli) => cli,
+        Err(e) => {
+            let code = match e.kind() {
+                ErrorKind::DisplayHelp
+                | ErrorKind::DisplayVersion
+                | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand => 0,
+                _ => 4,
+            };
+            let _ = e.print();
+            std::process::exit(code);
+        }
+    };
+    init_color(cli.no_color);
+    DRY_RUN.store(cli.dry_run, Ordering::Relaxed);
 
     let result = match cli.command {
         Commands::Status { events } => cmd_status(events),
+        Commands::Doctor => cmd_doctor(),
         Commands::Reminders(cmd) => match cmd {
             RemindersCommands::Authorize => cmd_reminders_authorize(),
-            RemindersCommands::Lists => cmd_reminders_lists(),
+            RemindersCommands::Lists { writable_only } => cmd_reminders_lists(writable_only),
             RemindersCommands::List {
                 list,
                 incomplete,
                 completed,
+                completed_since,
+                overdue,
+                due_before,
                 all,
-            } => cmd_reminders_list(list, incomplete, completed, all),
+                limit,
+                refresh,
+                watch,
+                sort,
+            } => cmd_reminders_list(
+                list,
+                incomplete,
+                completed,
+                completed_since.as_deref(),
+                overdue,
+                due_before.as_deref(),
+                all,
+                limit,
+                refresh,
+                watch,
+                sort,
+            ),
             RemindersCommands::Add {
                 title,
                 notes,
                 list,
                 priority,
-            } => cmd_reminders_add(&title, notes.as_deref(), list.as_deref(), priority),
+                url,
+            } => cmd_reminders_add(
+                &title,
+                notes.as_deref(),
+                list.as_deref(),
+                priority,
+                url.as_deref(),
+            ),
+            RemindersCommands::Import { file, list } => {
+                cmd_reminders_import(file.as_deref(), list.as_deref())
+            }
             RemindersCommands::Update {
                 id,
                 title,
                 notes,
                 priority,
-            } => cmd_reminders_update(&id, title.as_deref(), notes.as_deref(), priority),
-            RemindersCommands::Complete { id } => cmd_reminders_complete(&id),
+                url,
+            } => cmd_reminders_update(
+                &id,
+                title.as_deref(),
+                notes.as_deref(),
+                priority,
+                url.as_deref(),
+            ),
+            RemindersCommands::Complete { id, at } => cmd_reminders_complete(&id, at.as_deref()),
+            RemindersCommands::Search {
+                query,
+                list,
+                incomplete,
+                completed,
+                #[cfg(feature = "regex")]
+                regex,
+            } => {
+                #[cfg(not(feature = "regex"))]
+                let regex = false;
+                cmd_reminders_search(&query, list, incomplete, completed, regex)
+            }
+            RemindersCommands::CompleteAll {
+                list,
+                text,
+                due_before,
+            } => cmd_reminders_complete_all(list, text, due_before.as_deref()),
             RemindersCommands::Uncomplete { id } => cmd_reminders_uncomplete(&id),
-            RemindersCommands::Delete { id, force } => cmd_reminders_delete(&id, force),
+            RemindersCommands::Delete {
+                ids,
+                completed,
+                list,
+                force,
+            } => cmd_reminders_delete(&ids, completed, list.as_deref(), force),
+            RemindersCommands::Duplicate { id, list } => {
+                cmd_reminders_duplicate(&id, list.as_deref())
+            }
+            RemindersCommands::Geofence {
+                id,
+                title,
+                lat,
+                lon,
+                radius,
+                leave,
+            } => cmd_reminders_geofence(&id, title, lat, lon, radius, leave),
+            RemindersCommands::Move { id, list } => cmd_reminders_move(&id, &list),
+            RemindersCommands::Snooze { id, by } => cmd_reminders_snooze(&id, &by),
+            RemindersCommands::Reschedule { id, due } => cmd_reminders_reschedule(&id, &due),
             RemindersCommands::Show { id } => cmd_reminders_show(&id),
         },
         Commands::Events(cmd) => match cmd {
             EventsCommands::Authorize => cmd_events_authorize(),
-            EventsCommands::Calendars => cmd_events_calendars(),
+            EventsCommands::Calendars { writable_only } => cmd_events_calendars(writable_only),
+            EventsCommands::CreateCalendar {
+                title,
+                source,
+                color,
+            } => cmd_events_create_calendar(&title, &source, color),
             EventsCommands::List {
                 today,
                 days,
                 calendar,
                 all,
-            } => cmd_events_list(today, days, calendar, all),
+                limit,
+                refresh,
+                no_all_day,
+                hide_canceled,
+                hide_declined,
+                watch,
+                sort,
+            } => {
+                let filter = EventFilter {
+                    exclude_all_day: no_all_day,
+                    exclude_canceled: hide_canceled,
+                    exclude_declined: hide_declined,
+                    ..Default::default()
+                };
+                cmd_events_list(
+                    today, days, calendar, all, limit, refresh, &filter, watch, sort,
+                )
+            }
             EventsCommands::Add {
                 title,
                 start,
@@ -251,6 +785,11 @@ fn main() {
                 location,
                 calendar,
                 all_day,
+                time_zone,
+                rrules,
+                alarms,
+                show_as,
+                url,
             } => cmd_events_add(
                 &title,
                 &start,
@@ -260,22 +799,155 @@ fn main() {
                 location.as_deref(),
                 calendar.as_deref(),
                 all_day,
+                time_zone.as_deref(),
+                &rrules,
+                &alarms,
+                show_as,
+                url.as_deref(),
+            ),
+            EventsCommands::Alarm {
+                id,
+                before,
+                at,
+                remove,
+                clear,
+            } => cmd_events_alarm(&id, before.as_deref(), at.as_deref(), remove, clear),
+            EventsCommands::Geofence {
+                id,
+                title,
+                lat,
+                lon,
+                radius,
+                leave,
+            } => cmd_events_geofence(&id, title, lat.zip(lon), radius, leave),
+            EventsCommands::Shift { id, by } => cmd_events_shift(&id, &by, false),
+            EventsCommands::Extend { id, by } => cmd_events_shift(&id, &by, true),
+            EventsCommands::Repeat { id, rrules } => cmd_events_repeat(&id, &rrules),
+            EventsCommands::Delete { ids, series, force } => cmd_events_delete(&ids, series, force),
+            EventsCommands::Occurrences { id, days } => cmd_events_occurrences(&id, days),
+            EventsCommands::Freebusy { days, calendar } => cmd_events_freebusy(days, calendar),
+            EventsCommands::Search {
+                query,
+                days,
+                past,
+                calendar,
+            } => cmd_events_search(&query, days, past, calendar),
+            EventsCommands::Free {
+                days,
+                min,
+                hours,
+                weekends,
+                calendar,
+            } => cmd_events_free(
+                days,
+                &min,
+                hours.map(|hours| WorkingHours { weekends, ..hours }),
+                calendar,
             ),
-            EventsCommands::Delete { id, force } => cmd_events_delete(&id, force),
+            EventsCommands::Duplicate {
+                id,
+                start,
+                calendar,
+            } => cmd_events_duplicate(&id, &start, calendar.as_deref()),
             EventsCommands::Show { id } => cmd_events_show(&id),
         },
     };
 
     if let Err(e) = result {
         eprintln!("Error: {}", e);
-        std::process::exit(1);
+        std::process::exit(exit_code(&e));
+    }
+
+    if cli.dry_run {
+        eprintln!("\nDry run: nothing was saved.");
     }
 }
 
+// Maps an error to the exit code documented in --help
+fn exit_code(error: &EventKitError) -> i32 {
+    match error {
+        EventKitError::AuthorizationDenied
+        | EventKitError::AuthorizationRestricted
+        | EventKitError::AuthorizationNotDetermined
+        | EventKitError::AuthorizationRequestFailed(_) => 2,
+        EventKitError::CalendarNotFound(_)
+        | EventKitError::ItemNotFound(_)
+        | EventKitError::SourceNotFound(_)
+        | EventKitError::NoDefaultCalendar => 3,
+        EventKitError::InvalidDateRange
+        | EventKitError::InvalidDate(_)
+        | EventKitError::InvalidInput(_)
+        | EventKitError::InvalidUrl(_)
+        | EventKitError::InvalidLocation(_)
+        | EventKitError::InvalidTimeZone(_)
+        | EventKitError::InvalidPriority(_)
+        | EventKitError::AmbiguousCalendar { .. }
+        | EventKitError::CalendarWrongType { .. }
+        | EventKitError::NoDueDate(_)
+        | EventKitError::UnsupportedRecurrence(_)
+        | EventKitError::InvalidRRule(_)
+        | EventKitError::AvailabilityUnsupported { .. } => 4,
+        #[cfg(feature = "regex")]
+        EventKitError::InvalidPattern(_) => 4,
+        EventKitError::SaveFailed(_)
+        | EventKitError::DeleteFailed(_)
+        | EventKitError::SourceReadOnly(_) => 5,
+        _ => 1,
+    }
+}
+
+static DRY_RUN: AtomicBool = AtomicBool::new(false);
+
+// Creates a reminders manager that honors --dry-run and forgives loosely typed list names
+fn reminders_manager() -> RemindersManager {
+    let mut manager = RemindersManager::new();
+    manager.set_dry_run(DRY_RUN.load(Ordering::Relaxed));
+    manager.set_fuzzy_calendar_titles(true);
+    manager
+}
+
+// Creates an events manager that honors --dry-run and forgives loosely typed calendar names
+fn events_manager() -> EventsManager {
+    let mut manager = EventsManager::new();
+    manager.set_dry_run(DRY_RUN.load(Ordering::Relaxed));
+    manager.set_fuzzy_calendar_titles(true);
+    manager
+}
+
 // ============================================================================
-// Status command
+// Status and doctor commands
 // ============================================================================
 
+// What to do about an authorization status, or None if access is fine
+fn access_hint(status: AuthorizationStatus, events: bool) -> Option<String> {
+    let (command, pane) = if events {
+        ("events", "Calendars")
+    } else {
+        ("reminders", "Reminders")
+    };
+    match status {
+        AuthorizationStatus::NotDetermined => Some(format!(
+            "Use 'eventkit {} authorize' to request access.",
+            command
+        )),
+        AuthorizationStatus::Denied => Some(format!(
+            "Access was denied. Enable it in System Settings > Privacy & Security > {}.",
+            pane
+        )),
+        AuthorizationStatus::Restricted => Some(
+            "Access is restricted by a device policy (e.g. MDM). \
+             Contact your administrator to allow access."
+                .to_string(),
+        ),
+        AuthorizationStatus::WriteOnly => Some(format!(
+            "Only write access was granted. Allow full access in \
+             System Settings > Privacy & Security > {} to read items.",
+            pane
+        )),
+        AuthorizationStatus::FullAccess => None,
+    }
+}
+
 fn cmd_status(events: bool) -> Result<(), EventKitError> {
     let (kind, status) = if events {
         ("Calendar Events", EventsManager::authorization_status())
@@ -284,29 +956,83 @@ fn cmd_status(events: bool) -> Result<(), EventKitError> {
     };
 
     println!("{} Authorization Status: {}", kind, status);
+    if let Some(hint) = access_hint(status, events) {
+        println!("\n{}", hint);
+    }
 
-    match status {
-        AuthorizationStatus::NotDetermined => {
-            println!(
-                "\nUse 'eventkit {} authorize' to request access.",
-                if events { "events" } else { "reminders" }
-            );
-        }
-        AuthorizationStatus::Denied => {
-            println!("\nAccess was denied. Please enable access in:");
-            println!(
-                "System Settings > Privacy & Security > {}",
-                if events { "Calendars" } else { "Reminders" }
-            );
-        }
-        AuthorizationStatus::Restricted => {
-            println!("\nAccess is restricted by system policy.");
-        }
-        AuthorizationStatus::FullAccess => {
-            println!("\nFull access granted.");
-        }
-        AuthorizationStatus::WriteOnly => {
-            println!("\nWrite-only access granted.");
+    Ok(())
+}
+
+fn cmd_doctor() -> Result<(), EventKitError> {
+    let report = EventKit::new().diagnostics();
+    let auth = report.authorization;
+    let mut hints = Vec::new();
+
+    let check = |ok: bool| if ok { "✓" } else { "✗" };
+
+    println!("EventKit diagnostics:\n");
+    println!(
+        "  macOS {}{}",
+        report.macos_version,
+        if auth.supports_full_access {
+            " (separate full-access permission)"
+        } else {
+            ""
+        }
+    );
+
+    for (kind, status, events) in [
+        ("Reminders", auth.reminders, false),
+        ("Calendar", auth.events, true),
+    ] {
+        let hint = access_hint(status, events);
+        println!("  {} {} access: {}", check(hint.is_none()), kind, status);
+        hints.extend(hint.map(|h| format!("{} access is {}. {}", kind, status, h)));
+    }
+
+    for (kind, count, has_default, settings) in [
+        (
+            "reminder lists",
+            report.reminder_lists,
+            report.has_default_reminder_list,
+            "Reminders > Settings > Default List",
+        ),
+        (
+            "calendars",
+            report.event_calendars,
+            report.has_default_calendar,
+            "Calendar > Settings > General > Default Calendar",
+        ),
+    ] {
+        let Some(count) = count else {
+            println!("  ✗ {}: unknown without access", kind);
+            continue;
+        };
+        println!("  {} {} {}", check(count > 0), count, kind);
+        println!(
+            "  {} Default for new items: {}",
+            check(has_default),
+            if has_default { "set" } else { "missing" }
+        );
+        if count == 0 {
+            hints.push(format!(
+                "No {} found. Add an account in System Settings > Internet Accounts.",
+                kind
+            ));
+        } else if !has_default {
+            hints.push(format!(
+                "No default set for new items. Choose one in {}.",
+                settings
+            ));
+        }
+    }
+
+    if hints.is_empty() {
+        println!("\nEverything looks good.");
+    } else {
+        println!("\nHints:");
+        for hint in hints {
+            println!("  - {}", hint);
         }
     }
 
@@ -318,20 +1044,25 @@ fn cmd_status(events: bool) -> Result<(), EventKitError> {
 // ============================================================================
 
 fn cmd_reminders_authorize() -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
+    let manager = reminders_manager();
 
     println!("Requesting access to Reminders...");
 
     match manager.request_access() {
-        Ok(true) => {
+        Ok(access) if access.granted => {
             println!("✓ Access granted!");
             Ok(())
         }
-        Ok(false) => {
-            println!("✗ Access denied.");
-            println!("\nTo grant access, go to:");
-            println!("System Settings > Privacy & Security > Reminders");
-            Err(EventKitError::AuthorizationDenied)
+        Ok(access) => {
+            println!("✗ Access not granted ({}).", access.status);
+            if let Some(hint) = access_hint(access.status, false) {
+                println!("\n{}", hint);
+            }
+            if access.status == AuthorizationStatus::Restricted {
+                Err(EventKitError::AuthorizationRestricted)
+            } else {
+                Err(EventKitError::AuthorizationDenied)
+            }
         }
         Err(e) => {
             println!("✗ Failed to request access: {}", e);
@@ -340,9 +1071,13 @@ fn cmd_reminders_authorize() -> Result<(), EventKitError> {
     }
 }
 
-fn cmd_reminders_lists() -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
-    let calendars = manager.list_calendars()?;
+fn cmd_reminders_lists(writable_only: bool) -> Result<(), EventKitError> {
+    let manager = reminders_manager();
+    let calendars = if writable_only {
+        manager.list_writable_calendars()?
+    } else {
+        manager.list_calendars()?
+    };
 
     if calendars.is_empty() {
         println!("No reminder lists found.");
@@ -369,64 +1104,122 @@ fn cmd_reminders_lists() -> Result<(), EventKitError> {
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn cmd_reminders_list(
     list_filter: Option<Vec<String>>,
     incomplete: bool,
     show_completed: bool,
+    completed_since: Option<&str>,
+    overdue: bool,
+    due_before: Option<&str>,
     show_all: bool,
+    limit: Option<usize>,
+    refresh: bool,
+    watch: bool,
+    sort: Option<SortOrder>,
 ) -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
-
-    let reminders = if incomplete {
-        manager.fetch_incomplete_reminders()?
-    } else if let Some(ref lists) = list_filter {
-        let list_refs: Vec<&str> = lists.iter().map(|s| s.as_str()).collect();
-        manager.fetch_reminders(Some(&list_refs))?
-    } else {
-        manager.fetch_all_reminders()?
+    let parse = |s: &str| {
+        parse_datetime(s)
+            .ok_or_else(|| EventKitError::InvalidDate(format!("'{}'. {}", s, DATE_FORMAT_HINT)))
     };
-
-    let reminders: Vec<_> = if !incomplete && !show_completed && !show_all {
-        reminders.into_iter().filter(|r| !r.completed).collect()
-    } else if show_completed && !show_all {
-        reminders.into_iter().filter(|r| r.completed).collect()
-    } else {
-        reminders
-    };
-
-    if reminders.is_empty() {
-        println!("No reminders found.");
-        return Ok(());
+    let completed_since = completed_since.map(parse).transpose()?;
+    let due_before = due_before.map(parse).transpose()?;
+    let list_refs: Option<Vec<&str>> = list_filter
+        .as_ref()
+        .map(|lists| lists.iter().map(|s| s.as_str()).collect());
+
+    let manager = reminders_manager();
+    if refresh {
+        manager.refresh();
     }
 
-    println!("Reminders ({}):\n", reminders.len());
+    let render = || -> Result<(), EventKitError> {
+        let reminders = if let Some(since) = completed_since {
+            manager.fetch_completed_reminders(since, Local::now(), list_refs.as_deref())?
+        } else if overdue {
+            let mut reminders = manager.fetch_overdue_reminders()?;
+            if let Some(ref lists) = list_filter {
+                reminders.retain(|r| r.calendar_title.as_ref().is_some_and(|t| lists.contains(t)));
+            }
+            reminders
+        } else if incomplete || due_before.is_some() {
+            manager.fetch_incomplete_reminders_due(None, due_before, list_refs.as_deref())?
+        } else if list_refs.is_some() {
+            manager.fetch_reminders(list_refs.as_deref())?
+        } else {
+            manager.fetch_all_reminders()?
+        };
 
-    for reminder in reminders {
-        let status = if reminder.completed { "✓" } else { "○" };
-        let priority_str = match reminder.priority {
-            0 => String::new(),
-            1..=4 => " !!!".to_string(),
-            5 => " !!".to_string(),
-            _ => " !".to_string(),
+        // These were already filtered by the fetch
+        let prefiltered = completed_since.is_some() || overdue || due_before.is_some();
+        let mut reminders: Vec<_> = if prefiltered {
+            reminders
+        } else if !incomplete && !show_completed && !show_all {
+            reminders.into_iter().filter(|r| !r.completed).collect()
+        } else if show_completed && !show_all {
+            reminders.into_iter().filter(|r| r.completed).collect()
+        } else {
+            reminders
         };
 
-        println!("  {} {}{}", status, reminder.title, priority_str);
+        match (sort, limit) {
+            (Some(sort), Some(limit)) => sort.sort_reminders_limited(&mut reminders, limit),
+            (Some(sort), None) => sort.sort_reminders(&mut reminders),
+            (None, Some(limit)) => reminders.truncate(limit),
+            (None, None) => {}
+        }
 
-        if show_all {
-            if let Some(ref notes) = reminder.notes {
-                let truncated: String = notes.chars().take(60).collect();
-                let suffix = if notes.len() > 60 { "..." } else { "" };
-                println!("      Notes: {}{}", truncated, suffix);
-            }
-            if let Some(ref cal) = reminder.calendar_title {
-                println!("      List: {}", cal);
+        if reminders.is_empty() {
+            println!("No reminders found.");
+            return Ok(());
+        }
+
+        println!("Reminders ({}):\n", reminders.len());
+
+        for reminder in reminders {
+            let status = if reminder.completed {
+                ansi(GREEN, "✓")
+            } else {
+                "○".to_string()
+            };
+            let priority_str = match reminder.priority.level() {
+                Priority::None => String::new(),
+                Priority::High => format!(" {}", ansi(RED, "!!!")),
+                Priority::Medium => format!(" {}", ansi(YELLOW, "!!")),
+                _ => format!(" {}", ansi(BLUE, "!")),
+            };
+
+            println!("  {} {}{}", status, reminder.title, priority_str);
+
+            if show_all {
+                if let Some(ref notes) = reminder.notes {
+                    println!("      Notes: {}", truncate(notes, 60));
+                }
+                if let Some(ref cal) = reminder.calendar_title {
+                    println!("      List: {}", cal);
+                }
+                if let Some(done) = reminder.completion_date {
+                    println!("      Completed: {}", done.format("%Y-%m-%d %H:%M"));
+                }
+                println!("      ID: {}", reminder.identifier);
             }
-            println!("      ID: {}", reminder.identifier);
         }
-    }
 
-    if !show_all {
-        println!("\nUse --all to see more details.");
+        if !show_all {
+            println!("\nUse --all to see more details.");
+        }
+
+        Ok(())
+    };
+
+    render()?;
+
+    if watch {
+        let (notify, changes) = mpsc::channel();
+        let _subscription = manager.on_change(move || {
+            let _ = notify.send(());
+        });
+        watch_changes(&changes, render)?;
     }
 
     Ok(())
@@ -436,19 +1229,11 @@ fn cmd_reminders_add(
     title: &str,
     notes: Option<&str>,
     list: Option<&str>,
-    priority: Option<usize>,
+    priority: Option<Priority>,
+    url: Option<&str>,
 ) -> Result<(), EventKitError> {
-    if let Some(p) = priority
-        && p > 9
-    {
-        eprintln!("Priority must be between 0 and 9");
-        return Err(EventKitError::SaveFailed(
-            "Invalid priority value".to_string(),
-        ));
-    }
-
-    let manager = RemindersManager::new();
-    let reminder = manager.create_reminder(title, notes, list, priority)?;
+    let manager = reminders_manager();
+    let reminder = manager.create_reminder(title, notes, list, priority, &[], None, url)?;
 
     println!("✓ Created reminder: {}", reminder.title);
     println!("  ID: {}", reminder.identifier);
@@ -459,67 +1244,364 @@ fn cmd_reminders_add(
     Ok(())
 }
 
+fn cmd_reminders_import(file: Option<&str>, list: Option<&str>) -> Result<(), EventKitError> {
+    let text = match file {
+        Some(path) if path != "-" => std::fs::read_to_string(path),
+        _ => std::io::read_to_string(std::io::stdin()),
+    }
+    .map_err(|e| EventKitError::InvalidInput(format!("couldn't read input: {}", e)))?;
+
+    let new: Vec<NewReminder> = text
+        .lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty())
+        .map(|title| NewReminder {
+            calendar_title: list.map(str::to_string),
+            ..NewReminder::new(title)
+        })
+        .collect();
+    if new.is_empty() {
+        println!("Nothing to import.");
+        return Ok(());
+    }
+
+    let manager = reminders_manager();
+    let results = manager.create_reminders(&new)?;
+
+    let mut failed = 0;
+    for (reminder, outcome) in new.iter().zip(results) {
+        match outcome {
+            Ok(item) => println!("✓ Created: {} ({})", item.title, item.identifier),
+            Err(e) => {
+                failed += 1;
+                println!("✗ Failed: {}: {}", reminder.title, e);
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(EventKitError::SaveFailed(format!(
+            "{} reminder(s) could not be created",
+            failed
+        )));
+    }
+
+    Ok(())
+}
+
 fn cmd_reminders_update(
     id: &str,
     title: Option<&str>,
     notes: Option<&str>,
-    priority: Option<usize>,
+    priority: Option<Priority>,
+    url: Option<&str>,
 ) -> Result<(), EventKitError> {
-    if title.is_none() && notes.is_none() && priority.is_none() {
-        eprintln!("No updates specified. Use --title, --notes, or --priority.");
+    if title.is_none() && notes.is_none() && priority.is_none() && url.is_none() {
+        eprintln!("No updates specified. Use --title, --notes, --priority, or --url.");
         return Ok(());
     }
 
-    if let Some(p) = priority
-        && p > 9
-    {
-        eprintln!("Priority must be between 0 and 9");
-        return Err(EventKitError::SaveFailed(
-            "Invalid priority value".to_string(),
-        ));
-    }
-
-    let manager = RemindersManager::new();
-    let reminder = manager.update_reminder(id, title, notes, None, priority)?;
+    let url = url.map(|u| Some(u).filter(|u| !u.is_empty()));
+    let manager = reminders_manager();
+    let reminder = manager.update_reminder(&id.into(), title, notes, None, priority, None, url)?;
 
     println!("✓ Updated reminder: {}", reminder.title);
 
     Ok(())
 }
 
-fn cmd_reminders_complete(id: &str) -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
-    let reminder = manager.complete_reminder(id)?;
+fn cmd_reminders_complete(id: &str, at: Option<&str>) -> Result<(), EventKitError> {
+    let manager = reminders_manager();
+    let reminder = match at {
+        Some(at_str) => {
+            let at = parse_datetime(at_str).ok_or_else(|| {
+                EventKitError::InvalidDate(format!("'{}'. {}", at_str, DATE_FORMAT_HINT))
+            })?;
+            manager.complete_reminder_at(&id.into(), at)?
+        }
+        None => manager.complete_reminder(&id.into())?,
+    };
     println!("✓ Completed: {}", reminder.title);
+    if let (Some(_), Some(done)) = (at, reminder.completion_date) {
+        println!("  At: {}", done.format("%Y-%m-%d %H:%M"));
+    }
+    Ok(())
+}
+
+fn cmd_reminders_search(
+    query: &str,
+    list: Option<Vec<String>>,
+    incomplete: bool,
+    completed: bool,
+    regex: bool,
+) -> Result<(), EventKitError> {
+    let scope = ReminderFilter {
+        calendar_titles: list,
+        completed: match (incomplete, completed) {
+            (true, _) => Some(false),
+            (_, true) => Some(true),
+            _ => None,
+        },
+        ..Default::default()
+    };
+
+    let manager = reminders_manager();
+    let reminders = if regex {
+        #[cfg(feature = "regex")]
+        {
+            manager.search_reminders_regex(query, &scope)?
+        }
+        #[cfg(not(feature = "regex"))]
+        unreachable!("--regex is only available with the regex feature")
+    } else {
+        manager.search_reminders(query, &scope)?
+    };
+
+    if reminders.is_empty() {
+        println!("No reminders found.");
+        return Ok(());
+    }
+
+    println!("Reminders matching '{}' ({}):\n", query, reminders.len());
+    for reminder in reminders {
+        let status = if reminder.completed {
+            ansi(GREEN, "✓")
+        } else {
+            "○".to_string()
+        };
+        let list = reminder.calendar_title.as_deref().unwrap_or("Unknown");
+        println!("  {} {} [{}]", status, reminder.title, list);
+        println!("      ID: {}", reminder.identifier);
+    }
+    Ok(())
+}
+
+fn cmd_reminders_complete_all(
+    list: Option<String>,
+    text: Option<String>,
+    due_before: Option<&str>,
+) -> Result<(), EventKitError> {
+    if list.is_none() && text.is_none() && due_before.is_none() {
+        eprintln!("No filters specified. Use --list, --text, or --due-before.");
+        return Ok(());
+    }
+
+    let due_before = due_before
+        .map(|s| {
+            parse_datetime(s)
+                .ok_or_else(|| EventKitError::InvalidDate(format!("'{}'. {}", s, DATE_FORMAT_HINT)))
+        })
+        .transpose()?;
+    let filter = ReminderFilter {
+        calendar_titles: list.map(|l| vec![l]),
+        text,
+        due_before,
+        completed: Some(false),
+    };
+
+    let manager = reminders_manager();
+    let count = manager.complete_matching(&filter)?;
+    println!("✓ Completed {} reminder(s)", count);
     Ok(())
 }
 
 fn cmd_reminders_uncomplete(id: &str) -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
-    let reminder = manager.uncomplete_reminder(id)?;
+    let manager = reminders_manager();
+    let reminder = manager.uncomplete_reminder(&id.into())?;
     println!("○ Marked incomplete: {}", reminder.title);
     Ok(())
 }
 
-fn cmd_reminders_delete(id: &str, force: bool) -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
-    let reminder = manager.get_reminder(id)?;
+fn cmd_reminders_delete(
+    ids: &[String],
+    completed: bool,
+    list: Option<&str>,
+    force: bool,
+) -> Result<(), EventKitError> {
+    let manager = reminders_manager();
+
+    let mut targets: Vec<(ReminderId, String)> = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+
+    for id in ids {
+        match manager.get_reminder(&id.as_str().into()) {
+            Ok(reminder) => targets.push((reminder.identifier, reminder.title)),
+            Err(EventKitError::ItemNotFound(_)) => missing.push(id.clone()),
+            Err(e) => return Err(e),
+        }
+    }
+
+    if completed || list.is_some() {
+        let reminders = match list {
+            Some(list) => manager.fetch_reminders(Some(&[list]))?,
+            None => manager.fetch_all_reminders()?,
+        };
+        targets.extend(
+            reminders
+                .into_iter()
+                .filter(|r| !completed || r.completed)
+                .map(|r| (r.identifier, r.title)),
+        );
+    } else if ids.is_empty() {
+        eprintln!("Nothing to delete. Pass reminder IDs, --completed, or --list.");
+        return Ok(());
+    }
+
+    // An id can also match --completed or --list; delete it only once
+    let mut seen = HashSet::new();
+    targets.retain(|(id, _)| seen.insert(id.clone()));
+
+    for id in &missing {
+        println!("✗ Not found: {}", id);
+    }
+
+    if targets.is_empty() {
+        if !missing.is_empty() {
+            return Err(EventKitError::ItemNotFound(missing.join(", ")));
+        }
+        println!("No reminders to delete.");
+        return Ok(());
+    }
 
     if !force {
-        println!("Delete reminder: \"{}\"?", reminder.title);
+        println!("Delete {} reminder(s)?", targets.len());
+        for (_, title) in &targets {
+            println!("  • {}", title);
+        }
         println!("This action cannot be undone. Use --force to skip this prompt.");
         return Ok(());
     }
 
-    manager.delete_reminder(id)?;
-    println!("✓ Deleted: {}", reminder.title);
+    let target_ids: Vec<_> = targets.iter().map(|(id, _)| id.clone()).collect();
+    let results = manager.delete_reminders(&target_ids)?;
+    report_deletions(&targets, results, missing.len())
+}
+
+fn cmd_reminders_duplicate(id: &str, list: Option<&str>) -> Result<(), EventKitError> {
+    let manager = reminders_manager();
+    let reminder = manager.duplicate_reminder(&id.into(), list)?;
+
+    println!("✓ Duplicated reminder: {}", reminder.title);
+    println!("  ID: {}", reminder.identifier);
+    if let Some(ref cal) = reminder.calendar_title {
+        println!("  List: {}", cal);
+    }
+
+    Ok(())
+}
+
+fn cmd_reminders_move(id: &str, list: &str) -> Result<(), EventKitError> {
+    let manager = reminders_manager();
+    let list_id: CalendarId = list.into();
+    let reminder = if manager.get_calendar_by_id(&list_id).is_ok() {
+        manager.move_reminder_to_calendar(&id.into(), &list_id)?
+    } else {
+        manager.move_reminder(&id.into(), list)?
+    };
+
+    println!("✓ Moved reminder: {}", reminder.title);
+    if let Some(cal) = reminder.calendar_title {
+        println!("  List: {}", cal);
+    }
 
     Ok(())
 }
 
+fn cmd_reminders_snooze(id: &str, by_str: &str) -> Result<(), EventKitError> {
+    let by = parse_duration(by_str).ok_or_else(|| {
+        EventKitError::InvalidDate(format!(
+            "'{}'. Use a duration like 30m, 2h, 1d or 1w",
+            by_str
+        ))
+    })?;
+
+    let manager = reminders_manager();
+    let reminder = manager.snooze_reminder(&id.into(), by)?;
+    print_rescheduled(&reminder);
+    Ok(())
+}
+
+fn cmd_reminders_reschedule(id: &str, due_str: &str) -> Result<(), EventKitError> {
+    let due = parse_datetime(due_str).ok_or_else(|| {
+        EventKitError::InvalidDate(format!("'{}'. {}", due_str, DATE_FORMAT_HINT))
+    })?;
+
+    let manager = reminders_manager();
+    let reminder = manager.reschedule_reminder(&id.into(), due)?;
+    print_rescheduled(&reminder);
+    Ok(())
+}
+
+fn print_rescheduled(reminder: &ReminderItem) {
+    println!("✓ Rescheduled: {}", reminder.title);
+    if let Some(due) = reminder.due_date {
+        println!("  Due: {}", due.format("%Y-%m-%d %H:%M"));
+    }
+}
+
+fn cmd_reminders_geofence(
+    id: &str,
+    title: String,
+    latitude: f64,
+    longitude: f64,
+    radius: f64,
+    leave: bool,
+) -> Result<(), EventKitError> {
+    let location = StructuredLocation {
+        title,
+        latitude,
+        longitude,
+        radius,
+    };
+    let alarm = geofence_alarm(location, leave);
+
+    let manager = reminders_manager();
+    let reminder = manager.add_reminder_alarm(&id.into(), alarm.clone())?;
+
+    println!("✓ Added alarm to: {}", reminder.title);
+    println!("  {}", describe_alarm(&alarm));
+
+    Ok(())
+}
+
+// Builds the proximity alarm for a geofence command
+fn geofence_alarm(location: StructuredLocation, leave: bool) -> Alarm {
+    if leave {
+        Alarm::on_departure(location)
+    } else {
+        Alarm::on_arrival(location)
+    }
+}
+
+// One-line description of an alarm for `show` output
+fn describe_alarm(alarm: &Alarm) -> String {
+    match alarm {
+        Alarm::Relative(offset) if offset.num_seconds() == 0 => "At the time".to_string(),
+        Alarm::Relative(offset) => {
+            let when = if offset.num_seconds() < 0 {
+                "before"
+            } else {
+                "after"
+            };
+            format!("{} min {}", offset.num_minutes().abs(), when)
+        }
+        Alarm::Absolute(at) => format!("At {}", at.format("%Y-%m-%d %H:%M")),
+        Alarm::Proximity { trigger, location } => {
+            let verb = match trigger {
+                Proximity::Enter => "Arriving at",
+                Proximity::Leave => "Leaving",
+            };
+            format!(
+                "{} {} ({:.5}, {:.5})",
+                verb, location.title, location.latitude, location.longitude
+            )
+        }
+    }
+}
+
 fn cmd_reminders_show(id: &str) -> Result<(), EventKitError> {
-    let manager = RemindersManager::new();
-    let reminder = manager.get_reminder(id)?;
+    let manager = reminders_manager();
+    let reminder = manager.get_reminder(&id.into())?;
 
     println!("Reminder Details:\n");
     println!("  Title:     {}", reminder.title);
@@ -533,14 +1615,26 @@ fn cmd_reminders_show(id: &str) -> Result<(), EventKitError> {
     );
     println!(
         "  Priority:  {}",
-        match reminder.priority {
-            0 => "None".to_string(),
-            1..=4 => format!("High ({})", reminder.priority),
-            5 => "Medium".to_string(),
-            _ => format!("Low ({})", reminder.priority),
+        match reminder.priority.level() {
+            Priority::None => "None".to_string(),
+            Priority::High => format!("High ({})", reminder.priority.value()),
+            Priority::Medium => "Medium".to_string(),
+            _ => format!("Low ({})", reminder.priority.value()),
         }
     );
 
+    if let Some(due) = reminder.due_date {
+        println!("  Due:       {}", due.format("%Y-%m-%d %H:%M"));
+    }
+
+    for rule in &reminder.recurrence_rules {
+        println!("  Repeats:   {}", rule);
+    }
+
+    if let Some(done) = reminder.completion_date {
+        println!("  Completed: {}", done.format("%Y-%m-%d %H:%M"));
+    }
+
     if let Some(ref notes) = reminder.notes {
         println!("  Notes:     {}", notes);
     }
@@ -549,7 +1643,25 @@ fn cmd_reminders_show(id: &str) -> Result<(), EventKitError> {
         println!("  List:      {}", cal);
     }
 
+    if let Some(ref url) = reminder.url {
+        println!("  URL:       {}", url);
+    }
+
+    for alarm in &reminder.alarms {
+        println!("  Alarm:     {}", describe_alarm(alarm));
+    }
+
+    if let Some(created) = reminder.creation_date {
+        println!("  Created:   {}", created.format("%Y-%m-%d %H:%M"));
+    }
+    if let Some(modified) = reminder.last_modified_date {
+        println!("  Modified:  {}", modified.format("%Y-%m-%d %H:%M"));
+    }
+
     println!("  ID:        {}", reminder.identifier);
+    if let Some(ref external_id) = reminder.external_identifier {
+        println!("  External:  {}", external_id);
+    }
 
     Ok(())
 }
@@ -559,20 +1671,25 @@ fn cmd_reminders_show(id: &str) -> Result<(), EventKitError> {
 // ============================================================================
 
 fn cmd_events_authorize() -> Result<(), EventKitError> {
-    let manager = EventsManager::new();
+    let manager = events_manager();
 
     println!("Requesting access to Calendar...");
 
     match manager.request_access() {
-        Ok(true) => {
+        Ok(access) if access.granted => {
             println!("✓ Access granted!");
             Ok(())
         }
-        Ok(false) => {
-            println!("✗ Access denied.");
-            println!("\nTo grant access, go to:");
-            println!("System Settings > Privacy & Security > Calendars");
-            Err(EventKitError::AuthorizationDenied)
+        Ok(access) => {
+            println!("✗ Access not granted ({}).", access.status);
+            if let Some(hint) = access_hint(access.status, true) {
+                println!("\n{}", hint);
+            }
+            if access.status == AuthorizationStatus::Restricted {
+                Err(EventKitError::AuthorizationRestricted)
+            } else {
+                Err(EventKitError::AuthorizationDenied)
+            }
         }
         Err(e) => {
             println!("✗ Failed to request access: {}", e);
@@ -581,9 +1698,29 @@ fn cmd_events_authorize() -> Result<(), EventKitError> {
     }
 }
 
-fn cmd_events_calendars() -> Result<(), EventKitError> {
-    let manager = EventsManager::new();
-    let calendars = manager.list_calendars()?;
+fn cmd_events_create_calendar(
+    title: &str,
+    source: &CalendarSource,
+    color: Option<CalendarColor>,
+) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let calendar = manager.create_calendar(title, source, color)?;
+
+    println!("✓ Created calendar: {}", calendar.title);
+    if let Some(source) = &calendar.source {
+        println!("  Source: {}", source);
+    }
+    println!("  ID: {}", calendar.identifier);
+    Ok(())
+}
+
+fn cmd_events_calendars(writable_only: bool) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let calendars = if writable_only {
+        manager.list_writable_calendars()?
+    } else {
+        manager.list_calendars()?
+    };
 
     if calendars.is_empty() {
         println!("No calendars found.");
@@ -599,7 +1736,12 @@ fn cmd_events_calendars() -> Result<(), EventKitError> {
         } else {
             " (read-only)"
         };
-        println!("  • {} [{}]{}", cal.title, source, modifiable);
+        let kind = if cal.is_subscription() || cal.is_birthday() {
+            format!(" ({})", cal.calendar_type)
+        } else {
+            String::new()
+        };
+        println!("  • {} [{}]{}{}", cal.title, source, kind, modifiable);
         println!("    ID: {}", cal.identifier);
     }
 
@@ -610,90 +1752,340 @@ fn cmd_events_calendars() -> Result<(), EventKitError> {
     Ok(())
 }
 
+#[allow(clippy::too_many_arguments)]
 fn cmd_events_list(
     today: bool,
     days: i64,
     calendar_filter: Option<Vec<String>>,
     show_all: bool,
+    limit: Option<usize>,
+    refresh: bool,
+    filter: &EventFilter,
+    watch: bool,
+    sort: SortOrder,
 ) -> Result<(), EventKitError> {
-    let manager = EventsManager::new();
-
-    let events = if today {
-        manager.fetch_today_events()?
-    } else if let Some(ref cals) = calendar_filter {
-        let cal_refs: Vec<&str> = cals.iter().map(|s| s.as_str()).collect();
-        let now = Local::now();
-        let end = now + Duration::days(days);
-        manager.fetch_events(now, end, Some(&cal_refs))?
-    } else {
-        manager.fetch_upcoming_events(days)?
-    };
-
-    if events.is_empty() {
-        println!("No events found.");
-        return Ok(());
+    let manager = events_manager();
+    if refresh {
+        manager.refresh();
     }
 
-    println!("Events ({}):\n", events.len());
+    let render = || -> Result<(), EventKitError> {
+        let mut events = if today {
+            let mut events = manager.fetch_today_events()?;
+            events.retain(|e| filter.matches(e));
+            events
+        } else {
+            let cal_refs: Option<Vec<&str>> = calendar_filter
+                .as_ref()
+                .map(|cals| cals.iter().map(|s| s.as_str()).collect());
+            let now = Local::now();
+            let end = now + Duration::days(days);
+            // Sort before limiting so --limit keeps the first N in the chosen order
+            manager.fetch_events(now, end, cal_refs.as_deref(), Some(filter), None)?
+        };
+        sort.sort_events(&mut events);
+        if let Some(limit) = limit {
+            events.truncate(limit);
+        }
 
-    let mut current_date = String::new();
-    for event in events {
-        let event_date = event.start_date.format("%Y-%m-%d").to_string();
-        if event_date != current_date {
-            current_date = event_date.clone();
-            println!("\n  📅 {}", event.start_date.format("%A, %B %d, %Y"));
+        if events.is_empty() {
+            println!("No events found.");
+            return Ok(());
         }
 
-        let time_str = if event.all_day {
-            "All day".to_string()
+        println!("Events ({}):\n", events.len());
+
+        // Only look up calendar colors when they'll actually be shown
+        let colors: HashMap<CalendarId, CalendarColor> = if color_enabled() {
+            manager
+                .list_calendars()?
+                .into_iter()
+                .filter_map(|cal| Some((cal.identifier, cal.color?)))
+                .collect()
         } else {
-            format!(
-                "{} - {}",
-                event.start_date.format("%H:%M"),
-                event.end_date.format("%H:%M")
-            )
+            HashMap::new()
         };
 
-        println!("     {} {}", time_str, event.title);
+        for day in Agenda::from_events(events).days {
+            let heading = day.date.format("%A, %B %d, %Y").to_string();
+            println!("\n  📅 {}", ansi(BOLD, &heading));
 
-        if show_all {
-            if let Some(ref location) = event.location {
-                println!("        📍 {}", location);
-            }
-            if let Some(ref notes) = event.notes {
-                let truncated: String = notes.chars().take(50).collect();
-                let suffix = if notes.len() > 50 { "..." } else { "" };
-                println!("        📝 {}{}", truncated, suffix);
-            }
-            if let Some(ref cal) = event.calendar_title {
-                println!("        🗂  {}", cal);
+            for event in day.all_day.iter().chain(&day.timed) {
+                print_agenda_event(event, &colors, show_all);
             }
-            println!("        ID: {}", event.identifier);
         }
+
+        if !show_all {
+            println!("\nUse --all to see more details.");
+        }
+
+        Ok(())
+    };
+
+    render()?;
+
+    if watch {
+        let (notify, changes) = mpsc::channel();
+        let _subscription = manager.on_change(move || {
+            let _ = notify.send(());
+        });
+        watch_changes(&changes, render)?;
     }
 
-    if !show_all {
-        println!("\nUse --all to see more details.");
+    Ok(())
+}
+
+// Prints one event row (plus details with --all) under an agenda day heading
+fn print_agenda_event(
+    event: &EventItem,
+    colors: &HashMap<CalendarId, CalendarColor>,
+    show_all: bool,
+) {
+    let time_str = if event.all_day {
+        "All day".to_string()
+    } else {
+        format!(
+            "{} - {}",
+            event.start_date.format("%H:%M"),
+            event.end_date.format("%H:%M")
+        )
+    };
+
+    let calendar_color = event
+        .calendar_identifier
+        .as_ref()
+        .and_then(|id| colors.get(id).copied());
+    let bell = if event.has_alarms { " 🔔" } else { "" };
+    let repeat = if event.has_recurrence { " ↻" } else { "" };
+    let status = match event.status {
+        EventStatus::Canceled => format!(" {}", ansi(RED, "(canceled)")),
+        EventStatus::Tentative => format!(" {}", ansi(YELLOW, "(tentative)")),
+        _ => String::new(),
+    };
+    println!(
+        "     {} {}{}{}{}",
+        time_str,
+        paint(&event.title, calendar_color),
+        status,
+        repeat,
+        bell
+    );
+
+    if show_all {
+        if let Some(summary) = event.recurrence_summary() {
+            println!("        ↻  {}", summary);
+        }
+        if let Some(ref location) = event.location {
+            println!("        📍 {}", location);
+        }
+        if let Some(ref notes) = event.notes {
+            println!("        📝 {}", truncate(notes, 50));
+        }
+        if let Some(ref cal) = event.calendar_title {
+            println!("        🗂  {}", cal);
+        }
+        println!("        ID: {}", event.identifier);
     }
+}
 
+// Parses the value of --priority
+fn parse_priority(value: &str) -> Result<Priority, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "none" => Ok(Priority::None),
+        "high" => Ok(Priority::High),
+        "medium" => Ok(Priority::Medium),
+        "low" => Ok(Priority::Low),
+        other => other
+            .parse::<u8>()
+            .ok()
+            .filter(|n| *n <= 9)
+            .map(Priority::from)
+            .ok_or_else(|| "expected none, high, medium, low, or a number 0-9".to_string()),
+    }
+}
+
+// Parses the value of --show-as
+fn parse_availability(value: &str) -> Result<EventAvailability, String> {
+    match value.to_ascii_lowercase().as_str() {
+        "busy" => Ok(EventAvailability::Busy),
+        "free" => Ok(EventAvailability::Free),
+        "tentative" => Ok(EventAvailability::Tentative),
+        "unavailable" => Ok(EventAvailability::Unavailable),
+        _ => Err("expected one of: busy, free, tentative, unavailable".to_string()),
+    }
+}
+
+// Parses the value of --hours, e.g. "09:00-17:00"
+fn parse_working_hours(value: &str) -> Result<WorkingHours, String> {
+    let error = || "expected a range like 09:00-17:00".to_string();
+    let (start, end) = value.split_once('-').ok_or_else(error)?;
+    let time = |s: &str| NaiveTime::parse_from_str(s.trim(), "%H:%M").map_err(|_| error());
+    let (start, end) = (time(start)?, time(end)?);
+    if end <= start {
+        return Err("the end of the hours must be after the start".to_string());
+    }
+    Ok(WorkingHours {
+        start,
+        end,
+        weekends: false,
+    })
+}
+
+// Parses the value of --source
+fn parse_calendar_source(value: &str) -> Result<CalendarSource, String> {
+    Ok(match value.to_ascii_lowercase().as_str() {
+        "" => return Err("expected default, icloud, local or an account name".to_string()),
+        "default" => CalendarSource::Default,
+        "icloud" => CalendarSource::ICloud,
+        "local" => CalendarSource::Local,
+        _ => CalendarSource::Named(value.to_string()),
+    })
+}
+
+// Parses a color such as "#FF9500"
+fn parse_color(value: &str) -> Result<CalendarColor, String> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    let channel = |i: usize| {
+        hex.get(i..i + 2)
+            .and_then(|c| u8::from_str_radix(c, 16).ok())
+            .ok_or_else(|| "expected a color like #FF9500".to_string())
+    };
+    if hex.len() != 6 {
+        return Err("expected a color like #FF9500".to_string());
+    }
+    Ok(CalendarColor {
+        red: channel(0)?,
+        green: channel(2)?,
+        blue: channel(4)?,
+    })
+}
+
+// Parses the value of --sort
+fn parse_sort_order(value: &str) -> Result<SortOrder, String> {
+    match value {
+        "start" => Ok(SortOrder::StartAsc),
+        "start-desc" => Ok(SortOrder::StartDesc),
+        "title" => Ok(SortOrder::TitleAsc),
+        "priority" => Ok(SortOrder::PriorityThenDue),
+        "created" => Ok(SortOrder::CreatedDesc),
+        "modified" => Ok(SortOrder::ModifiedDesc),
+        "none" => Ok(SortOrder::None),
+        _ => Err(
+            "expected one of: start, start-desc, title, priority, created, modified, none"
+                .to_string(),
+        ),
+    }
+}
+
+// How often the watch loop wakes up to check for Ctrl-C
+const WATCH_POLL: StdDuration = StdDuration::from_millis(100);
+// Changes arriving this close together are coalesced into a single re-render
+const WATCH_DEBOUNCE: StdDuration = StdDuration::from_millis(300);
+
+static INTERRUPTED: AtomicBool = AtomicBool::new(false);
+
+extern "C" fn on_interrupt(_signal: libc::c_int) {
+    INTERRUPTED.store(true, Ordering::SeqCst);
+}
+
+// Re-renders after each burst of store changes until Ctrl-C. Returning (rather
+// than letting SIGINT kill the process) lets the caller's subscription drop
+// and remove its observer.
+fn watch_changes(
+    changes: &mpsc::Receiver<()>,
+    render: impl Fn() -> Result<(), EventKitError>,
+) -> Result<(), EventKitError> {
+    unsafe {
+        libc::signal(
+            libc::SIGINT,
+            on_interrupt as extern "C" fn(libc::c_int) as *const () as libc::sighandler_t,
+        );
+    }
+
+    println!("\nWatching for changes (Ctrl-C to stop)...");
+
+    while !INTERRUPTED.load(Ordering::SeqCst) {
+        eventkit::run_loop_for(WATCH_POLL);
+        if changes.try_iter().count() == 0 {
+            continue;
+        }
+
+        // Wait until the store has been quiet for the debounce window
+        let mut last_change = Instant::now();
+        while last_change.elapsed() < WATCH_DEBOUNCE && !INTERRUPTED.load(Ordering::SeqCst) {
+            eventkit::run_loop_for(WATCH_POLL);
+            if changes.try_iter().count() > 0 {
+                last_change = Instant::now();
+            }
+        }
+
+        // Clear the screen and redraw from the top
+        print!("\x1B[2J\x1B[H");
+        render()?;
+        println!(
+            "\nUpdated {}. Watching for changes (Ctrl-C to stop)...",
+            Local::now().format("%H:%M:%S")
+        );
+    }
+
+    println!("\nStopped watching.");
     Ok(())
 }
 
-fn parse_datetime(s: &str) -> Option<chrono::DateTime<Local>> {
-    // Try "YYYY-MM-DD HH:MM" format first
-    if let Ok(dt) = NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M") {
-        return Local.from_local_datetime(&dt).single();
+// ============================================================================
+// Terminal output
+// ============================================================================
+
+static COLOR: AtomicBool = AtomicBool::new(false);
+
+const BOLD: &str = "1";
+const RED: &str = "31";
+const GREEN: &str = "32";
+const YELLOW: &str = "33";
+const BLUE: &str = "34";
+
+// Color is only used on a terminal, and never when --no-color or the
+// NO_COLOR environment variable (https://no-color.org) asks for plain output
+fn init_color(no_color: bool) {
+    let env_disabled = std::env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty());
+    let enabled = !no_color && !env_disabled && std::io::stdout().is_terminal();
+    COLOR.store(enabled, Ordering::Relaxed);
+}
+
+fn color_enabled() -> bool {
+    COLOR.load(Ordering::Relaxed)
+}
+
+// Wraps text in an SGR escape code when color is enabled
+fn ansi(code: &str, text: &str) -> String {
+    if color_enabled() {
+        format!("\x1B[{}m{}\x1B[0m", code, text)
+    } else {
+        text.to_string()
     }
+}
 
-    // Try "YYYY-MM-DD" format (for all-day events)
-    if let Ok(date) = chrono::NaiveDate::parse_from_str(s, "%Y-%m-%d") {
-        let dt = date.and_hms_opt(0, 0, 0)?;
-        return Local.from_local_datetime(&dt).single();
+// Colors text with a calendar's own color (24-bit) when color is enabled
+fn paint(text: &str, color: Option<CalendarColor>) -> String {
+    match color {
+        Some(c) => ansi(&format!("38;2;{};{};{}", c.red, c.green, c.blue), text),
+        None => text.to_string(),
     }
+}
 
-    None
+// Shortens text to `max` characters (not bytes, so multi-byte text and emoji
+// are never split), adding "..." when something was cut
+fn truncate(text: &str, max: usize) -> String {
+    if text.chars().count() <= max {
+        return text.to_string();
+    }
+    let shortened: String = text.chars().take(max).collect();
+    format!("{}...", shortened)
 }
 
+const DATE_FORMAT_HINT: &str = "Use YYYY-MM-DD [HH:MM], MM/DD/YYYY [HH:MM], \
+     today/tomorrow/<weekday> [HH:MM], or an offset like +30m, +2h, +1d";
+
 #[allow(clippy::too_many_arguments)]
 fn cmd_events_add(
     title: &str,
@@ -704,18 +2096,19 @@ fn cmd_events_add(
     location: Option<&str>,
     calendar: Option<&str>,
     all_day: bool,
+    time_zone: Option<&str>,
+    rrules: &[String],
+    alarms: &[String],
+    show_as: Option<EventAvailability>,
+    url: Option<&str>,
 ) -> Result<(), EventKitError> {
     let start = parse_datetime(start_str).ok_or_else(|| {
-        EventKitError::SaveFailed(
-            "Invalid start date format. Use YYYY-MM-DD HH:MM or YYYY-MM-DD".to_string(),
-        )
+        EventKitError::InvalidDate(format!("'{}'. {}", start_str, DATE_FORMAT_HINT))
     })?;
 
     let end = if let Some(end_s) = end_str {
         parse_datetime(end_s).ok_or_else(|| {
-            EventKitError::SaveFailed(
-                "Invalid end date format. Use YYYY-MM-DD HH:MM or YYYY-MM-DD".to_string(),
-            )
+            EventKitError::InvalidDate(format!("'{}'. {}", end_s, DATE_FORMAT_HINT))
         })?
     } else if all_day {
         start + Duration::days(1)
@@ -723,8 +2116,27 @@ fn cmd_events_add(
         start + Duration::minutes(duration_mins)
     };
 
-    let manager = EventsManager::new();
-    let event = manager.create_event(title, start, end, notes, location, calendar, all_day)?;
+    let rules = rrules
+        .iter()
+        .map(|r| r.parse())
+        .collect::<Result<Vec<RecurrenceRule>, _>>()?;
+    let alarms = alarms
+        .iter()
+        .map(|a| alarm_before(a))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let manager = events_manager();
+    let event = manager
+        .create_event(
+            title, start, end, notes, location, calendar, all_day, None, &alarms, time_zone,
+            &rules, show_as, url,
+        )
+        .map_err(|e| match e {
+            EventKitError::InvalidDateRange => {
+                EventKitError::InvalidDate("end must be after start".to_string())
+            }
+            e => e,
+        })?;
 
     println!("✓ Created event: {}", event.title);
     println!("  Start: {}", event.start_date.format("%Y-%m-%d %H:%M"));
@@ -733,40 +2145,474 @@ fn cmd_events_add(
     if let Some(cal) = event.calendar_title {
         println!("  Calendar: {}", cal);
     }
+    for rule in &event.recurrence_rules {
+        println!("  Repeats: {}", rule);
+    }
+
+    Ok(())
+}
+
+fn cmd_events_occurrences(id: &str, days: i64) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let now = Local::now();
+    let occurrences = manager.fetch_occurrences(&id.into(), now, now + Duration::days(days))?;
+
+    let Some(first) = occurrences.first() else {
+        println!("No occurrences in the next {} day(s).", days);
+        return Ok(());
+    };
+
+    println!("{} ({} occurrence(s)):\n", first.title, occurrences.len());
+    for event in &occurrences {
+        let time = if event.all_day {
+            event.start_date.format("%Y-%m-%d").to_string()
+        } else {
+            event.start_date.format("%Y-%m-%d %H:%M").to_string()
+        };
+        let moved = match event.occurrence_date {
+            Some(original) if original != event.start_date => {
+                format!(" (moved from {})", original.format("%Y-%m-%d %H:%M"))
+            }
+            _ if event.is_detached => " (edited)".to_string(),
+            _ => String::new(),
+        };
+        println!("  • {}{}", time, moved);
+    }
+    Ok(())
+}
+
+fn cmd_events_search(
+    query: &str,
+    days: i64,
+    past: i64,
+    calendar_filter: Option<Vec<String>>,
+) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let cal_refs: Option<Vec<&str>> = calendar_filter
+        .as_ref()
+        .map(|cals| cals.iter().map(|s| s.as_str()).collect());
+    let now = Local::now();
+    let events = manager.search_events(
+        query,
+        now - Duration::days(past),
+        now + Duration::days(days),
+        cal_refs.as_deref(),
+    )?;
+
+    if events.is_empty() {
+        println!("No events found.");
+        return Ok(());
+    }
+
+    println!("Events matching '{}' ({}):\n", query, events.len());
+    for event in events {
+        let time = if event.all_day {
+            event.start_date.format("%Y-%m-%d").to_string()
+        } else {
+            event.start_date.format("%Y-%m-%d %H:%M").to_string()
+        };
+        let calendar = event.calendar_title.as_deref().unwrap_or("Unknown");
+        println!("  • {} {} [{}]", time, event.title, calendar);
+        println!("      ID: {}", event.identifier);
+    }
+    Ok(())
+}
+
+fn cmd_events_freebusy(
+    days: i64,
+    calendar_filter: Option<Vec<String>>,
+) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let cal_refs: Option<Vec<&str>> = calendar_filter
+        .as_ref()
+        .map(|cals| cals.iter().map(|s| s.as_str()).collect());
+    let now = Local::now();
+    let blocks = manager.free_busy(now, now + Duration::days(days), cal_refs.as_deref())?;
+
+    if blocks.is_empty() {
+        println!("Free for the next {} day(s).", days);
+        return Ok(());
+    }
+
+    println!("Busy ({}):\n", blocks.len());
+
+    for block in blocks {
+        let end_format = if block.end.date_naive() == block.start.date_naive() {
+            "%H:%M"
+        } else {
+            "%Y-%m-%d %H:%M"
+        };
+        println!(
+            "  • {} - {}",
+            block.start.format("%Y-%m-%d %H:%M"),
+            block.end.format(end_format)
+        );
+    }
+
+    Ok(())
+}
+
+fn cmd_events_free(
+    days: i64,
+    min_str: &str,
+    working_hours: Option<WorkingHours>,
+    calendar_filter: Option<Vec<String>>,
+) -> Result<(), EventKitError> {
+    let min_duration = parse_duration(min_str).ok_or_else(|| {
+        EventKitError::InvalidDate(format!(
+            "'{}'. Use a duration like 30m, 2h, 1d or 1w",
+            min_str
+        ))
+    })?;
+
+    let manager = events_manager();
+    let cal_refs: Option<Vec<&str>> = calendar_filter
+        .as_ref()
+        .map(|cals| cals.iter().map(|s| s.as_str()).collect());
+    let now = Local::now();
+    let slots = manager.find_free_slots(
+        now,
+        now + Duration::days(days),
+        min_duration,
+        working_hours.as_ref(),
+        cal_refs.as_deref(),
+    )?;
+
+    if slots.is_empty() {
+        println!(
+            "No free slots of {} or more in the next {} day(s).",
+            min_str, days
+        );
+        return Ok(());
+    }
+
+    println!("Free ({}):\n", slots.len());
+
+    for (start, end) in slots {
+        let end_format = if end.date_naive() == start.date_naive() {
+            "%H:%M"
+        } else {
+            "%Y-%m-%d %H:%M"
+        };
+        println!(
+            "  • {} - {}",
+            start.format("%Y-%m-%d %H:%M"),
+            end.format(end_format)
+        );
+    }
+
+    Ok(())
+}
+
+// Parses a duration like "15m" into an alarm that fires that long before the start
+fn alarm_before(before: &str) -> Result<Alarm, EventKitError> {
+    let offset = parse_duration(before).ok_or_else(|| {
+        EventKitError::InvalidDate(format!(
+            "'{}'. Use a duration like 30m, 2h, 1d or 1w",
+            before
+        ))
+    })?;
+    Ok(Alarm::Relative(-offset))
+}
+
+fn cmd_events_alarm(
+    id: &str,
+    before: Option<&str>,
+    at: Option<&str>,
+    remove: bool,
+    clear: bool,
+) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let id: EventId = id.into();
+
+    let event = if clear {
+        manager.set_event_alarms(&id, &[])?
+    } else {
+        let alarm = match (before, at) {
+            (Some(before), _) => alarm_before(before)?,
+            (None, Some(at)) => Alarm::Absolute(parse_datetime(at).ok_or_else(|| {
+                EventKitError::InvalidDate(format!("'{}'. {}", at, DATE_FORMAT_HINT))
+            })?),
+            (None, None) => {
+                eprintln!("No alarm specified. Use --before, --at, or --clear.");
+                return Ok(());
+            }
+        };
+        if remove {
+            manager.remove_alarm(&id, &alarm)?
+        } else {
+            manager.add_alarm(&id, alarm)?
+        }
+    };
+
+    if event.alarms.is_empty() {
+        println!("✓ {} has no alarms", event.title);
+    } else {
+        println!("✓ Alarms for {}:", event.title);
+        for alarm in &event.alarms {
+            println!("    {}", describe_alarm(alarm));
+        }
+    }
+    Ok(())
+}
+
+fn cmd_events_geofence(
+    id: &str,
+    title: Option<String>,
+    coordinates: Option<(f64, f64)>,
+    radius: Option<f64>,
+    leave: bool,
+) -> Result<(), EventKitError> {
+    let manager = events_manager();
+    let id: EventId = id.into();
+
+    let mut location = match coordinates {
+        Some((latitude, longitude)) => StructuredLocation {
+            title: title.unwrap_or_default(),
+            latitude,
+            longitude,
+            radius: 0.0,
+        },
+        None => match manager.get_event(&id)?.structured_location {
+            Some(location) => location,
+            None => {
+                eprintln!("The event has no map location. Use --lat and --lon.");
+                return Ok(());
+            }
+        },
+    };
+    if let Some(radius) = radius {
+        location.radius = radius;
+    }
+    let alarm = geofence_alarm(location, leave);
+
+    let event = manager.add_alarm(&id, alarm.clone())?;
+
+    println!("✓ Added alarm to: {}", event.title);
+    println!("  {}", describe_alarm(&alarm));
 
     Ok(())
 }
 
-fn cmd_events_delete(id: &str, force: bool) -> Result<(), EventKitError> {
-    let manager = EventsManager::new();
-    let event = manager.get_event(id)?;
+fn cmd_events_shift(id: &str, by_str: &str, end_only: bool) -> Result<(), EventKitError> {
+    let by = parse_duration(by_str).ok_or_else(|| {
+        EventKitError::InvalidDate(format!(
+            "'{}'. Use a duration like 30m, 2h, 1d or 1w",
+            by_str
+        ))
+    })?;
+
+    let manager = events_manager();
+    let event = if end_only {
+        manager.extend_event(&id.into(), by)
+    } else {
+        manager.shift_event(&id.into(), by)
+    }
+    .map_err(|e| match e {
+        EventKitError::InvalidDateRange => {
+            EventKitError::InvalidDate("the event would end before it starts".to_string())
+        }
+        e => e,
+    })?;
+
+    println!(
+        "✓ {} now runs {} - {}",
+        event.title,
+        event.start_date.format("%Y-%m-%d %H:%M"),
+        event.end_date.format("%Y-%m-%d %H:%M")
+    );
+    Ok(())
+}
+
+fn cmd_events_repeat(id: &str, rrules: &[String]) -> Result<(), EventKitError> {
+    let rules = rrules
+        .iter()
+        .map(|r| r.parse())
+        .collect::<Result<Vec<RecurrenceRule>, _>>()?;
+
+    let manager = events_manager();
+    let event = manager.set_event_recurrence(&id.into(), &rules)?;
+    if event.recurrence_rules.is_empty() {
+        println!("✓ {} no longer repeats", event.title);
+    } else {
+        println!("✓ {} now repeats:", event.title);
+        for rrule in event.to_rrule_strings() {
+            println!("    {}", rrule);
+        }
+    }
+    Ok(())
+}
+
+fn cmd_events_delete(ids: &[String], series: bool, force: bool) -> Result<(), EventKitError> {
+    let manager = events_manager();
+
+    let mut targets: Vec<(EventId, String)> = Vec::new();
+    let mut missing: Vec<String> = Vec::new();
+
+    for id in ids {
+        match manager.get_event(&id.as_str().into()) {
+            Ok(event) if series && event.has_recurrence => targets.push((
+                event.identifier,
+                format!("{} (every occurrence)", event.title),
+            )),
+            Ok(event) => targets.push((event.identifier, event.title)),
+            Err(EventKitError::ItemNotFound(_)) => missing.push(id.clone()),
+            Err(e) => return Err(e),
+        }
+    }
+
+    // The same event can be passed more than once; delete it only once
+    let mut seen = HashSet::new();
+    targets.retain(|(id, _)| seen.insert(id.clone()));
+
+    for id in &missing {
+        println!("✗ Not found: {}", id);
+    }
+
+    if targets.is_empty() {
+        if !missing.is_empty() {
+            return Err(EventKitError::ItemNotFound(missing.join(", ")));
+        }
+        println!("No events to delete.");
+        return Ok(());
+    }
 
     if !force {
-        println!("Delete event: \"{}\"?", event.title);
+        println!("Delete {} event(s)?", targets.len());
+        for (_, title) in &targets {
+            println!("  • {}", title);
+        }
         println!("This action cannot be undone. Use --force to skip this prompt.");
         return Ok(());
     }
 
-    manager.delete_event(id)?;
-    println!("✓ Deleted: {}", event.title);
+    let target_ids: Vec<_> = targets.iter().map(|(id, _)| id.clone()).collect();
+    let span = if series {
+        Span::ThisAndFuture
+    } else {
+        Span::ThisEvent
+    };
+    let results = manager.delete_events(&target_ids, span)?;
+    report_deletions(&targets, results, missing.len())
+}
+
+fn report_deletions<Id: std::fmt::Display>(
+    targets: &[(Id, String)],
+    results: Vec<(Id, Result<(), EventKitError>)>,
+    already_failed: usize,
+) -> Result<(), EventKitError> {
+    let mut failed = already_failed;
+
+    for ((_, title), (id, outcome)) in targets.iter().zip(results) {
+        match outcome {
+            Ok(()) => println!("✓ Deleted: {}", title),
+            Err(e) => {
+                failed += 1;
+                println!("✗ Failed: {} ({}): {}", title, id, e);
+            }
+        }
+    }
+
+    if failed > 0 {
+        return Err(EventKitError::DeleteFailed(format!(
+            "{} item(s) could not be deleted",
+            failed
+        )));
+    }
+
+    Ok(())
+}
+
+fn cmd_events_duplicate(
+    id: &str,
+    start_str: &str,
+    calendar: Option<&str>,
+) -> Result<(), EventKitError> {
+    let start = parse_datetime(start_str).ok_or_else(|| {
+        EventKitError::InvalidDate(format!("'{}'. {}", start_str, DATE_FORMAT_HINT))
+    })?;
+
+    let manager = events_manager();
+    let event = manager.duplicate_event(&id.into(), start, calendar)?;
+
+    println!("✓ Duplicated event: {}", event.title);
+    println!("  Start: {}", event.start_date.format("%Y-%m-%d %H:%M"));
+    println!("  End:   {}", event.end_date.format("%Y-%m-%d %H:%M"));
+    println!("  ID: {}", event.identifier);
+    if let Some(ref cal) = event.calendar_title {
+        println!("  Calendar: {}", cal);
+    }
 
     Ok(())
 }
 
 fn cmd_events_show(id: &str) -> Result<(), EventKitError> {
-    let manager = EventsManager::new();
-    let event = manager.get_event(id)?;
+    let manager = events_manager();
+    // Fall back to the server-side id, which stays the same across devices
+    let event = match manager.get_event(&id.into()) {
+        Err(EventKitError::ItemNotFound(_)) => manager
+            .get_event_by_external_id(id)?
+            .into_iter()
+            .next()
+            .ok_or_else(|| EventKitError::ItemNotFound(id.to_string()))?,
+        result => result?,
+    };
 
     println!("Event Details:\n");
     println!("  Title:     {}", event.title);
-    println!("  Start:     {}", event.start_date.format("%Y-%m-%d %H:%M"));
-    println!("  End:       {}", event.end_date.format("%Y-%m-%d %H:%M"));
+    if let Some((first, last)) = event.all_day_dates() {
+        println!("  Start:     {}", first.format("%Y-%m-%d"));
+        println!("  End:       {}", last.format("%Y-%m-%d"));
+    } else {
+        println!("  Start:     {}", event.start_date.format("%Y-%m-%d %H:%M"));
+        println!("  End:       {}", event.end_date.format("%Y-%m-%d %H:%M"));
+    }
     println!("  All Day:   {}", if event.all_day { "Yes" } else { "No" });
+    if event.has_recurrence {
+        let note = if event.is_detached {
+            " (this occurrence was edited separately)"
+        } else {
+            ""
+        };
+        if event.recurrence_rules.is_empty() {
+            println!("  Repeats:   Yes{}", note);
+        }
+        for (rule, rrule) in event.recurrence_rules.iter().zip(event.to_rrule_strings()) {
+            println!("  Repeats:   {}{}", rule, note);
+            println!("             {}", rrule);
+        }
+    }
+
+    if let Some(ref zone) = event.time_zone {
+        let start = event.start_in_event_zone();
+        if start.offset().local_minus_utc() != event.start_date.offset().local_minus_utc() {
+            println!(
+                "  Time Zone: {} ({} - {} there)",
+                zone,
+                start.format("%Y-%m-%d %H:%M"),
+                event.end_in_event_zone().format("%H:%M")
+            );
+        } else {
+            println!("  Time Zone: {}", zone);
+        }
+    }
+    println!("  Show As:   {}", event.availability);
+    if event.status != EventStatus::None {
+        println!("  Status:    {}", event.status);
+    }
 
     if let Some(ref location) = event.location {
         println!("  Location:  {}", location);
     }
 
+    if let Some(ref url) = event.url {
+        println!("  URL:       {}", url);
+    }
+
+    if let Some(ref geo) = event.structured_location {
+        println!("  Coords:    {:.5}, {:.5}", geo.latitude, geo.longitude);
+    }
+
     if let Some(ref notes) = event.notes {
         println!("  Notes:     {}", notes);
     }
@@ -775,7 +2621,52 @@ fn cmd_events_show(id: &str) -> Result<(), EventKitError> {
         println!("  Calendar:  {}", cal);
     }
 
+    for alarm in &event.alarms {
+        println!("  Alarm:     {}", describe_alarm(alarm));
+    }
+
+    if let Some(ref organizer) = event.organizer {
+        println!("  Organizer: {}", participant_label(organizer));
+    }
+
+    if let Some(status) = event.my_participation_status {
+        println!("  You:       {}", status);
+    }
+
+    if !event.attendees.is_empty() {
+        println!("  Attendees:");
+        for attendee in &event.attendees {
+            println!(
+                "    • {} ({}, {})",
+                participant_label(attendee),
+                attendee.role,
+                attendee.status
+            );
+        }
+    }
+
     println!("  ID:        {}", event.identifier);
+    if let Some(created) = event.creation_date {
+        println!("  Created:   {}", created.format("%Y-%m-%d %H:%M"));
+    }
+    if let Some(modified) = event.last_modified_date {
+        println!("  Modified:  {}", modified.format("%Y-%m-%d %H:%M"));
+    }
+    if let Some(ref external_id) = event.external_identifier {
+        println!("  External:  {}", external_id);
+    }
 
     Ok(())
 }
+
+fn participant_label(participant: &Participant) -> String {
+    match (&participant.name, &participant.email) {
+        (Some(name), Some(email)) => format!("{} <{}>", name, email),
+        (Some(name), None) => name.clone(),
+        (None, Some(email)) => email.clone(),
+        (None, None) => participant
+            .url
+            .clone()
+            .unwrap_or_else(|| "Unknown".to_string()),
+    }
+}